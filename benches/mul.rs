@@ -1,4 +1,4 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use tiny_big_uint::BigUInt;
 
 #[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
@@ -10,6 +10,10 @@ fn max_mul(c: &mut Criterion) {
     c.bench_function("8bit Lower Multiplication", |b| {
         b.iter(|| <BigUInt<32>>::from(u128::MAX) * <BigUInt<32>>::from(100u32))
     });
+
+    c.bench_function("8bit 2048bit Multiplication", |b| {
+        b.iter(|| <BigUInt<256>>::MAX.wrapping_mul(<BigUInt<256>>::MAX))
+    });
 }
 
 #[cfg(feature = "16bit")]
@@ -21,6 +25,10 @@ fn max_mul(c: &mut Criterion) {
     c.bench_function("16bit Lower Multiplication", |b| {
         b.iter(|| <BigUInt<16>>::from(u128::MAX) * <BigUInt<16>>::from(100u32))
     });
+
+    c.bench_function("16bit 2048bit Multiplication", |b| {
+        b.iter(|| <BigUInt<128>>::MAX.wrapping_mul(<BigUInt<128>>::MAX))
+    });
 }
 
 #[cfg(feature = "32bit")]
@@ -32,6 +40,10 @@ fn max_mul(c: &mut Criterion) {
     c.bench_function("32bit Lower Multiplication", |b| {
         b.iter(|| <BigUInt<8>>::from(u128::MAX) * <BigUInt<8>>::from(100u32))
     });
+
+    c.bench_function("32bit 2048bit Multiplication", |b| {
+        b.iter(|| <BigUInt<64>>::MAX.wrapping_mul(<BigUInt<64>>::MAX))
+    });
 }
 
 #[cfg(feature = "64bit")]
@@ -43,7 +55,135 @@ fn max_mul(c: &mut Criterion) {
     c.bench_function("64bit Lower Multiplication", |b| {
         b.iter(|| <BigUInt<4>>::from(u128::MAX) * <BigUInt<4>>::from(100u32))
     });
+
+    c.bench_function("64bit 2048bit Multiplication", |b| {
+        b.iter(|| <BigUInt<32>>::MAX.wrapping_mul(<BigUInt<32>>::MAX))
+    });
+}
+
+/// A number with pseudo-random bits in all of its `N` words. With all bits set, Karatsuba would
+/// get away without its middle product.
+fn random<const N: usize>(mut state: u64) -> BigUInt<N> {
+    let mut x = BigUInt::MIN;
+
+    for _ in 0..BigUInt::<N>::BITS.div_ceil(64) {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        x = (x << 64usize) | BigUInt::from(state);
+    }
+
+    x
+}
+
+/// Widening multiplication of two `N` word numbers, which switches to Karatsuba at the threshold.
+fn widening_mul<const N: usize>(c: &mut Criterion, word_size: &str) {
+    let name = format!("{} {} words Widening Multiplication", word_size, N);
+    let (x, y) = (random::<N>(1), random::<N>(2));
+
+    c.bench_function(&name, |b| {
+        b.iter(|| black_box(x).widening_mul(black_box(y)))
+    });
+}
+
+/// Wrapping multiplication of two `N` word numbers, which switches to Karatsuba at the truncated
+/// threshold.
+fn wrapping_mul<const N: usize>(c: &mut Criterion, word_size: &str) {
+    let name = format!("{} {} words Wrapping Multiplication", word_size, N);
+    let (x, y) = (random::<N>(1), random::<N>(2));
+
+    c.bench_function(&name, |b| {
+        b.iter(|| black_box(x).wrapping_mul(black_box(y)))
+    });
+}
+
+/// Widening squaring of an `N` word number, which switches to Karatsuba at the square threshold.
+fn widening_square<const N: usize>(c: &mut Criterion, word_size: &str) {
+    let name = format!("{} {} words Widening Squaring", word_size, N);
+    let x = random::<N>(1);
+
+    c.bench_function(&name, |b| b.iter(|| black_box(x).widening_square()));
+}
+
+/// Wrapping squaring of an `N` word number, which switches to Karatsuba at the truncated square
+/// threshold.
+fn wrapping_square<const N: usize>(c: &mut Criterion, word_size: &str) {
+    let name = format!("{} {} words Wrapping Squaring", word_size, N);
+    let x = random::<N>(1);
+
+    c.bench_function(&name, |b| b.iter(|| black_box(x).wrapping_square()));
+}
+
+// Just below and at the Karatsuba thresholds of each word size
+
+#[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
+fn karatsuba(c: &mut Criterion) {
+    widening_mul::<39>(c, "8bit");
+    widening_mul::<40>(c, "8bit");
+    widening_mul::<80>(c, "8bit");
+
+    wrapping_mul::<191>(c, "8bit");
+    wrapping_mul::<192>(c, "8bit");
+
+    widening_square::<95>(c, "8bit");
+    widening_square::<96>(c, "8bit");
+    widening_square::<192>(c, "8bit");
+
+    wrapping_square::<383>(c, "8bit");
+    wrapping_square::<384>(c, "8bit");
+}
+
+#[cfg(feature = "16bit")]
+fn karatsuba(c: &mut Criterion) {
+    widening_mul::<55>(c, "16bit");
+    widening_mul::<56>(c, "16bit");
+    widening_mul::<112>(c, "16bit");
+
+    wrapping_mul::<511>(c, "16bit");
+    wrapping_mul::<512>(c, "16bit");
+
+    widening_square::<79>(c, "16bit");
+    widening_square::<80>(c, "16bit");
+    widening_square::<160>(c, "16bit");
+
+    wrapping_square::<1023>(c, "16bit");
+    wrapping_square::<1024>(c, "16bit");
+}
+
+#[cfg(feature = "32bit")]
+fn karatsuba(c: &mut Criterion) {
+    widening_mul::<55>(c, "32bit");
+    widening_mul::<56>(c, "32bit");
+    widening_mul::<112>(c, "32bit");
+
+    wrapping_mul::<511>(c, "32bit");
+    wrapping_mul::<512>(c, "32bit");
+
+    widening_square::<95>(c, "32bit");
+    widening_square::<96>(c, "32bit");
+    widening_square::<192>(c, "32bit");
+
+    wrapping_square::<1023>(c, "32bit");
+    wrapping_square::<1024>(c, "32bit");
+}
+
+#[cfg(feature = "64bit")]
+fn karatsuba(c: &mut Criterion) {
+    widening_mul::<47>(c, "64bit");
+    widening_mul::<48>(c, "64bit");
+    widening_mul::<96>(c, "64bit");
+
+    wrapping_mul::<511>(c, "64bit");
+    wrapping_mul::<512>(c, "64bit");
+
+    widening_square::<95>(c, "64bit");
+    widening_square::<96>(c, "64bit");
+    widening_square::<192>(c, "64bit");
+
+    wrapping_square::<1023>(c, "64bit");
+    wrapping_square::<1024>(c, "64bit");
 }
 
-criterion_group!(benches, max_mul);
+criterion_group!(benches, max_mul, karatsuba);
 criterion_main!(benches);
\ No newline at end of file
@@ -1,53 +1,297 @@
-use crate::{BigUInt, Word};
+use crate::{u128_to_words, BigUInt, Word};
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-impl<const NUM_WORDS: usize> Add<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
+/// Calculates `lhs + rhs + carry` in place for the little-endian words `rhs`, where a shorter
+/// `rhs` is zero-extended.
+///
+/// Returns whether the sum did not fit into `lhs`.
+pub(crate) fn carrying_add_words(
+    lhs: &mut [Word],
+    rhs: impl IntoIterator<Item = Word>,
+    mut carry: bool,
+) -> bool {
+    let mut rhs = rhs.into_iter();
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut carry = false;
-        let mut big_int = BigUInt::MIN;
+    for x in lhs.iter_mut() {
+        let y = match rhs.next() {
+            Some(y) => y,
+            // Nothing is left to add
+            None if !carry => return false,
+            None => 0,
+        };
 
-        // Go over all the words and declare the sum of each word.
-        for i in 0..NUM_WORDS {
-            let x = self.internal[i];
-            let y = rhs.internal[i];
+        let (new_num, carry_a) = x.overflowing_add(y);
+        let (new_num, carry_b) = new_num.overflowing_add(Word::from(carry));
 
-            // Filter between different situations
-            let (new_num, new_carry) = match (carry, x == Word::MAX) {
-                (false, _) => x.overflowing_add(y),
-                (true, true) => (y, true),
-                (true, false) => (x + 1).overflowing_add(y),
-            };
+        carry = carry_a || carry_b;
+        *x = new_num;
+    }
+
+    carry || rhs.any(|y| y != 0)
+}
+
+/// Calculates `lhs - rhs - borrow` in place for the little-endian words `rhs`, where a shorter
+/// `rhs` is zero-extended.
+///
+/// Returns whether a borrow was needed beyond the most significant word of `lhs`.
+pub(crate) fn borrowing_sub_words(
+    lhs: &mut [Word],
+    rhs: impl IntoIterator<Item = Word>,
+    mut borrow: bool,
+) -> bool {
+    let mut rhs = rhs.into_iter();
+
+    for x in lhs.iter_mut() {
+        let y = match rhs.next() {
+            Some(y) => y,
+            // Nothing is left to subtract
+            None if !borrow => return false,
+            None => 0,
+        };
+
+        let (new_num, borrow_a) = x.overflowing_sub(y);
+        let (new_num, borrow_b) = new_num.overflowing_sub(Word::from(borrow));
+
+        borrow = borrow_a || borrow_b;
+        *x = new_num;
+    }
+
+    borrow || rhs.any(|y| y != 0)
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Calculates `self + rhs + carry`, returning the sum and whether a carry came out of the most
+    /// significant word.
+    ///
+    /// This allows for chaining additions over multiple numbers.
+    pub fn carrying_add(mut self, rhs: Self, carry: bool) -> (Self, bool) {
+        let carry = self.carrying_add_assign(&rhs.internal, carry);
+        (self, carry)
+    }
+
+    /// Calculates `self + rhs + carry` in place for the little-endian words `rhs` of any length,
+    /// returning whether the sum did not fit.
+    fn carrying_add_assign(&mut self, rhs: &[Word], carry: bool) -> bool {
+        carrying_add_words(&mut self.internal, rhs.iter().copied(), carry)
+    }
+
+    /// Calculates `self - rhs - borrow`, returning the difference and whether a borrow was needed
+    /// beyond the most significant word.
+    ///
+    /// This allows for chaining subtractions over multiple numbers.
+    pub fn borrowing_sub(mut self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let borrow = self.borrowing_sub_assign(&rhs.internal, borrow);
+        (self, borrow)
+    }
+
+    /// Calculates `self - rhs - borrow` in place for the little-endian words `rhs` of any length,
+    /// returning whether a borrow was needed beyond the most significant word.
+    fn borrowing_sub_assign(&mut self, rhs: &[Word], borrow: bool) -> bool {
+        borrowing_sub_words(&mut self.internal, rhs.iter().copied(), borrow)
+    }
+
+    /// Calculates `self + rhs`, returning the sum along with whether an overflow occurred.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        self.carrying_add(rhs, false)
+    }
+
+    /// Calculates `self - rhs`, returning the difference along with whether an underflow
+    /// occurred.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// This results in `2^BITS - self` for any non-zero value and in `0` for `0`.
+    pub fn wrapping_neg(self) -> Self {
+        Self::MIN.wrapping_sub(self)
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at [`MAX`](crate::BigUInt::MAX)
+    /// instead of overflowing.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at
+    /// [`MIN`](crate::BigUInt::MIN) instead of overflowing.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::MIN)
+    }
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Add a single word onto `self` in one linear pass.
+    ///
+    /// Like the addition operator, this panics on overflow when `debug_assertions` are enabled
+    /// and wraps around otherwise.
+    pub fn add_word(mut self, rhs: Word) -> Self {
+        self.add_word_assign(rhs);
+        self
+    }
+
+    /// Subtract a single word from `self` in one linear pass.
+    ///
+    /// Like the subtraction operator, this panics on overflow when `debug_assertions` are
+    /// enabled and wraps around otherwise.
+    pub fn sub_word(mut self, rhs: Word) -> Self {
+        self.sub_word_assign(rhs);
+        self
+    }
+
+    /// Add a single word onto `self` in place.
+    fn add_word_assign(&mut self, rhs: Word) {
+        let mut carry = rhs;
 
-            carry = new_carry;
-            big_int.internal[i] = new_num;
+        // Stop as soon as there is nothing left to carry over
+        for word in self.internal.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+
+            let (new_num, overflow) = word.overflowing_add(carry);
+            *word = new_num;
+            carry = Word::from(overflow);
         }
 
-        big_int
+        if cfg!(debug_assertions) && carry != 0 {
+            panic!("attempt to add with overflow");
+        }
+    }
+
+    /// Subtract a single word from `self` in place.
+    fn sub_word_assign(&mut self, rhs: Word) {
+        let mut borrow = rhs;
+
+        // Stop as soon as there is nothing left to borrow
+        for word in self.internal.iter_mut() {
+            if borrow == 0 {
+                break;
+            }
+
+            let (new_num, overflow) = word.overflowing_sub(borrow);
+            *word = new_num;
+            borrow = Word::from(overflow);
+        }
+
+        if cfg!(debug_assertions) && borrow != 0 {
+            panic!("attempt to subtract with overflow");
+        }
     }
 }
-impl<const NUM_WORDS: usize> Sub<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        // Subtract by adding
-        let additive = (rhs ^ Self::MAX).increase();
-        additive + self
+
+impl<const NUM_WORDS: usize, const M: usize> AddAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn add_assign(&mut self, rhs: &BigUInt<M>) {
+        let overflow = self.carrying_add_assign(&rhs.internal, false);
+
+        if cfg!(debug_assertions) && overflow {
+            panic!("attempt to add with overflow");
+        }
     }
 }
+impl<const NUM_WORDS: usize, const M: usize> SubAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn sub_assign(&mut self, rhs: &BigUInt<M>) {
+        let overflow = self.borrowing_sub_assign(&rhs.internal, false);
 
-impl<const NUM_WORDS: usize> AddAssign<Self> for BigUInt<NUM_WORDS> {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = self.clone() + rhs;
+        if cfg!(debug_assertions) && overflow {
+            panic!("attempt to subtract with overflow");
+        }
     }
 }
 
-impl<const NUM_WORDS: usize> SubAssign<Self> for BigUInt<NUM_WORDS> {
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = self.clone() - rhs;
+impl<const NUM_WORDS: usize, const M: usize> Add<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn add(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut sum = *self;
+        sum += rhs;
+        sum
+    }
+}
+impl<const NUM_WORDS: usize, const M: usize> Sub<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn sub(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut difference = *self;
+        difference -= rhs;
+        difference
     }
 }
 
+forward_ref_binop!(Add, add, AddAssign, add_assign);
+forward_ref_binop!(Sub, sub, SubAssign, sub_assign);
+
+macro_rules! add_sub_primitive {
+    ($($utype:ty),*) => {$(
+        impl<const NUM_WORDS: usize> Add<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn add(mut self, rhs: $utype) -> Self::Output {
+                self += rhs;
+                self
+            }
+        }
+        impl<const NUM_WORDS: usize> Sub<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn sub(mut self, rhs: $utype) -> Self::Output {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl<const NUM_WORDS: usize> AddAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn add_assign(&mut self, rhs: $utype) {
+                if rhs as u128 <= Word::MAX as u128 {
+                    return self.add_word_assign(rhs as Word);
+                }
+
+                let overflow = self.carrying_add_assign(&u128_to_words(rhs as u128), false);
+                if cfg!(debug_assertions) && overflow {
+                    panic!("attempt to add with overflow");
+                }
+            }
+        }
+        impl<const NUM_WORDS: usize> SubAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn sub_assign(&mut self, rhs: $utype) {
+                if rhs as u128 <= Word::MAX as u128 {
+                    return self.sub_word_assign(rhs as Word);
+                }
+
+                let overflow = self.borrowing_sub_assign(&u128_to_words(rhs as u128), false);
+                if cfg!(debug_assertions) && overflow {
+                    panic!("attempt to subtract with overflow");
+                }
+            }
+        }
+    )*};
+}
+
+add_sub_primitive!(u8, u16, u32, u64, u128);
+
 #[test]
 fn add() {
     // Check it stays 0
@@ -122,3 +366,264 @@ fn sub() {
         }
     }
 }
+
+#[test]
+fn add_sub_primitive() {
+    for x in 65500..65600u32 {
+        assert_eq!(<BigUInt<16>>::from(x) + 250u8, BigUInt::from(x + 250));
+        assert_eq!(<BigUInt<16>>::from(x) + 1000u16, BigUInt::from(x + 1000));
+        assert_eq!(
+            <BigUInt<16>>::from(x) + 100_000u32,
+            BigUInt::from(x + 100_000)
+        );
+        assert_eq!(
+            <BigUInt<16>>::from(x) + u64::from(u32::MAX),
+            BigUInt::from(u64::from(x) + u64::from(u32::MAX))
+        );
+        assert_eq!(
+            <BigUInt<16>>::from(x) + (1u128 << 100),
+            BigUInt::from(u128::from(x) + (1u128 << 100))
+        );
+
+        assert_eq!(<BigUInt<16>>::from(x) - 250u8, BigUInt::from(x - 250));
+        assert_eq!(<BigUInt<16>>::from(x) - 1000u16, BigUInt::from(x - 1000));
+        assert_eq!(
+            <BigUInt<16>>::from(u128::MAX) - u128::from(x),
+            BigUInt::from(u128::MAX - u128::from(x))
+        );
+    }
+
+    let mut x = <BigUInt<16>>::from(40u32);
+    x += 5u64;
+    x -= 3u128;
+    assert_eq!(x, BigUInt::from(42u32));
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_primitive_overflow() {
+    let _ = <BigUInt<1>>::MIN + u128::MAX;
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_primitive_overflow() {
+    let _ = <BigUInt<1>>::MAX - u128::MAX;
+}
+#[test]
+fn add_word() {
+    for x in 65500..65600u32 {
+        for y in 250..256u16 {
+            assert_eq!(
+                <BigUInt<16>>::from(x).add_word(y as Word),
+                BigUInt::from(x + u32::from(y))
+            );
+        }
+    }
+
+    assert_eq!(BigUInt::<16>::MAX.add_word(0), BigUInt::MAX);
+}
+#[test]
+fn sub_word() {
+    for x in 65500..65600u32 {
+        for y in 250..256u16 {
+            assert_eq!(
+                <BigUInt<16>>::from(x).sub_word(y as Word),
+                BigUInt::from(x - u32::from(y))
+            );
+        }
+    }
+
+    assert_eq!(BigUInt::<16>::MIN.sub_word(0), BigUInt::MIN);
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_word_overflow() {
+    let _ = BigUInt::<16>::MAX.add_word(1);
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_word_overflow() {
+    let _ = BigUInt::<16>::MIN.sub_word(1);
+}
+#[test]
+fn overflowing_add() {
+    assert_eq!(
+        <BigUInt<16>>::from(40u32).overflowing_add(BigUInt::from(2u32)),
+        (BigUInt::from(42u32), false)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.overflowing_add(BigUInt::from(1u32)),
+        (BigUInt::MIN, true)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.overflowing_add(BigUInt::MAX),
+        (BigUInt::MAX.decrease(), true)
+    );
+
+    // Chain the carry into a next number
+    let (lo, carry) = BigUInt::<16>::MAX.carrying_add(BigUInt::MAX, false);
+    let (hi, carry) = <BigUInt<16>>::from(1u32).carrying_add(BigUInt::MIN, carry);
+    assert_eq!(
+        (lo, hi, carry),
+        (BigUInt::MAX.decrease(), BigUInt::from(2u32), false)
+    );
+}
+#[test]
+fn overflowing_sub() {
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).overflowing_sub(BigUInt::from(2u32)),
+        (BigUInt::from(40u32), false)
+    );
+    assert_eq!(
+        BigUInt::<16>::MIN.overflowing_sub(BigUInt::from(1u32)),
+        (BigUInt::MAX, true)
+    );
+
+    // Chain the borrow into a next number
+    let (lo, borrow) = <BigUInt<16>>::from(1u32).borrowing_sub(BigUInt::from(2u32), false);
+    let (hi, borrow) = <BigUInt<16>>::from(1u32).borrowing_sub(BigUInt::MIN, borrow);
+    assert_eq!((lo, hi, borrow), (BigUInt::MAX, BigUInt::MIN, false));
+}
+#[test]
+fn checked_add() {
+    assert_eq!(
+        <BigUInt<16>>::from(40u32).checked_add(BigUInt::from(2u32)),
+        Some(BigUInt::from(42u32))
+    );
+    assert_eq!(
+        BigUInt::MAX.checked_add(BigUInt::MIN),
+        Some(BigUInt::<16>::MAX)
+    );
+    assert_eq!(BigUInt::<16>::MAX.checked_add(BigUInt::from(1u32)), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_add(BigUInt::MAX), None);
+}
+#[test]
+fn checked_sub() {
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).checked_sub(BigUInt::from(2u32)),
+        Some(BigUInt::from(40u32))
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).checked_sub(BigUInt::from(42u32)),
+        Some(BigUInt::MIN)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(2u32).checked_sub(BigUInt::from(42u32)),
+        None
+    );
+    assert_eq!(BigUInt::<16>::MIN.checked_sub(BigUInt::from(1u32)), None);
+}
+
+#[test]
+fn saturating_add() {
+    assert_eq!(
+        <BigUInt<16>>::from(40u32).saturating_add(BigUInt::from(2u32)),
+        BigUInt::from(42u32)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.saturating_add(BigUInt::from(1u32)),
+        BigUInt::MAX
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.saturating_add(BigUInt::MAX),
+        BigUInt::MAX
+    );
+}
+#[test]
+fn saturating_sub() {
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).saturating_sub(BigUInt::from(2u32)),
+        BigUInt::from(40u32)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(2u32).saturating_sub(BigUInt::from(42u32)),
+        BigUInt::MIN
+    );
+    assert_eq!(
+        BigUInt::<16>::MIN.saturating_sub(BigUInt::MAX),
+        BigUInt::MIN
+    );
+}
+
+#[test]
+fn wrapping_add_sub() {
+    assert_eq!(
+        BigUInt::<16>::MAX.wrapping_add(BigUInt::from(2u32)),
+        BigUInt::from(1u32)
+    );
+    assert_eq!(
+        BigUInt::<16>::MIN.wrapping_sub(BigUInt::from(2u32)),
+        BigUInt::MAX.decrease()
+    );
+    assert_eq!(BigUInt::<16>::MIN.wrapping_neg(), BigUInt::MIN);
+    assert_eq!(<BigUInt<16>>::from(1u32).wrapping_neg(), BigUInt::<16>::MAX);
+    assert_eq!(
+        <BigUInt<16>>::from(42u32)
+            .wrapping_neg()
+            .wrapping_add(BigUInt::from(42u32)),
+        BigUInt::MIN
+    );
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_overflow() {
+    let _ = BigUInt::<16>::MAX + BigUInt::from(1u32);
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_overflow() {
+    let _ = BigUInt::<16>::MIN - BigUInt::from(1u32);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn add_sub_ref() {
+    let x = <BigUInt<16>>::from(40u32);
+    let y = <BigUInt<16>>::from(2u32);
+
+    assert_eq!(&x + &y, BigUInt::from(42u32));
+    assert_eq!(&x + y, BigUInt::from(42u32));
+    assert_eq!(x + &y, BigUInt::from(42u32));
+    assert_eq!(&x - &y, BigUInt::from(38u32));
+    assert_eq!(&x - y, BigUInt::from(38u32));
+    assert_eq!(x - &y, BigUInt::from(38u32));
+
+    let mut z = x;
+    z += &y;
+    z -= &x;
+    assert_eq!(z, y);
+}
+
+#[test]
+fn add_sub_mixed_width() {
+    let x = <BigUInt<64>>::MAX - <BigUInt<64>>::from(u128::MAX);
+    let y = <BigUInt<32>>::from(u128::MAX);
+
+    assert_eq!(x + &y, BigUInt::MAX);
+    assert_eq!(&<BigUInt<64>>::MAX - &y, x);
+
+    // A wider right-hand side only has to fit
+    let mut z = <BigUInt<16>>::from(40u32);
+    z += &<BigUInt<32>>::from(2u32);
+    assert_eq!(z, BigUInt::from(42u32));
+    z -= &<BigUInt<64>>::from(42u32);
+    assert_eq!(z, BigUInt::MIN);
+
+    // Carries propagate past the narrower width
+    let mut w = <BigUInt<64>>::from(1u32) << BigUInt::<32>::BITS;
+    w -= &<BigUInt<32>>::from(1u32);
+    assert_eq!(w, &<BigUInt<32>>::MAX);
+    w += &<BigUInt<32>>::from(1u32);
+    assert_eq!(w, <BigUInt<64>>::from(1u32) << BigUInt::<32>::BITS);
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_mixed_width_overflow() {
+    let _ = <BigUInt<16>>::MIN + &(<BigUInt<32>>::from(1u32) << BigUInt::<16>::BITS);
+}
@@ -0,0 +1,650 @@
+use crate::{BigUInt, Word, WORD_BITS};
+use core::ops::{Bound, RangeBounds};
+
+/// The amount of words needed to hold a [`u64`].
+const U64_WORDS: usize = 64 / WORD_BITS;
+
+/// Resolve `range` into its start and end, checking it against a BigUInt of `bits` bits.
+fn bit_range(range: impl RangeBounds<usize>, bits: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => Some(bits),
+    };
+
+    // A bound which overflows lies beyond any BigUInt
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => panic!("bit range out of range for a BigUInt of {} bits", bits),
+    };
+
+    if start > end {
+        panic!("bit range starts at {} but ends at {}", start, end);
+    }
+    if end > bits {
+        panic!(
+            "bit range end {} out of range for a BigUInt of {} bits",
+            end, bits
+        );
+    }
+
+    (start, end)
+}
+
+/// Resolve the bit `index` into its word index and the mask selecting it within that word,
+/// checking it against a BigUInt of `bits` bits.
+fn bit_position(index: usize, bits: usize) -> (usize, Word) {
+    if index >= bits {
+        panic!(
+            "bit index {} out of range for a BigUInt of {} bits",
+            index, bits
+        );
+    }
+
+    (index / WORD_BITS, 1 << (index % WORD_BITS))
+}
+
+/// Returns a word with the lowest `len` bits set, saturating at a full word.
+fn low_mask(len: usize) -> Word {
+    if len >= WORD_BITS {
+        Word::MAX
+    } else {
+        (1 << len) - 1
+    }
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Returns the word-sized window of bits starting at bit `offset`, where bits beyond the most
+    /// significant word are zero.
+    fn word_at_bit(&self, offset: usize) -> Word {
+        let word_index = offset / WORD_BITS;
+        let bit_shift = offset % WORD_BITS;
+
+        let right_word = self.internal.get(word_index).copied().unwrap_or(0);
+        if bit_shift == 0 {
+            return right_word;
+        }
+
+        let left_word = self.internal.get(word_index + 1).copied().unwrap_or(0);
+        (right_word >> bit_shift) | (left_word << (WORD_BITS - bit_shift))
+    }
+
+    /// Replaces the bits selected by `mask` in the word-sized window starting at bit `offset` by
+    /// those of `word`. The selected bits have to lie within `self`.
+    fn set_word_at_bit(&mut self, offset: usize, word: Word, mask: Word) {
+        let word_index = offset / WORD_BITS;
+        let bit_shift = offset % WORD_BITS;
+
+        let right_word = &mut self.internal[word_index];
+        *right_word = (*right_word & !(mask << bit_shift)) | ((word & mask) << bit_shift);
+
+        if bit_shift != 0 {
+            let mask = mask >> (WORD_BITS - bit_shift);
+
+            if mask != 0 {
+                let left_word = &mut self.internal[word_index + 1];
+                *left_word = (*left_word & !mask) | ((word >> (WORD_BITS - bit_shift)) & mask);
+            }
+        }
+    }
+
+    /// Returns the number of leading zeros, i.e. the number of zero bits above the most
+    /// significant set bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(1u8) << 200usize;
+    ///
+    /// assert_eq!(x.leading_zeros(), BigUInt::<32>::BITS - 201);
+    /// assert_eq!(<BigUInt<32>>::MIN.leading_zeros(), BigUInt::<32>::BITS);
+    /// ```
+    pub fn leading_zeros(&self) -> usize {
+        let mut zeros = 0;
+
+        for &word in self.internal.iter().rev() {
+            zeros += word.leading_zeros() as usize;
+            if word != 0 {
+                break;
+            }
+        }
+
+        zeros
+    }
+
+    /// Returns the number of trailing zeros, i.e. the number of zero bits below the least
+    /// significant set bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0b1100u8) << 200usize;
+    ///
+    /// assert_eq!(x.trailing_zeros(), 202);
+    /// assert_eq!(<BigUInt<32>>::MIN.trailing_zeros(), BigUInt::<32>::BITS);
+    /// ```
+    pub fn trailing_zeros(&self) -> usize {
+        let mut zeros = 0;
+
+        for &word in self.internal.iter() {
+            zeros += word.trailing_zeros() as usize;
+            if word != 0 {
+                break;
+            }
+        }
+
+        zeros
+    }
+
+    /// Returns the number of leading ones, i.e. the number of set bits above the most
+    /// significant zero bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::MAX >> 200usize;
+    ///
+    /// assert_eq!((!x).leading_ones(), 200);
+    /// assert_eq!(<BigUInt<32>>::MAX.leading_ones(), BigUInt::<32>::BITS);
+    /// ```
+    pub fn leading_ones(&self) -> usize {
+        let mut ones = 0;
+
+        for &word in self.internal.iter().rev() {
+            ones += word.leading_ones() as usize;
+            if word != Word::MAX {
+                break;
+            }
+        }
+
+        ones
+    }
+
+    /// Returns the number of trailing ones, i.e. the number of set bits below the least
+    /// significant zero bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::MAX << 200usize;
+    ///
+    /// assert_eq!((!x).trailing_ones(), 200);
+    /// assert_eq!(<BigUInt<32>>::MAX.trailing_ones(), BigUInt::<32>::BITS);
+    /// ```
+    pub fn trailing_ones(&self) -> usize {
+        let mut ones = 0;
+
+        for &word in self.internal.iter() {
+            ones += word.trailing_ones() as usize;
+            if word != Word::MAX {
+                break;
+            }
+        }
+
+        ones
+    }
+
+    /// Returns the number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0b1011u8) << 200usize;
+    ///
+    /// assert_eq!(x.count_ones(), 3);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.internal
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the number of zero bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0b1011u8) << 200usize;
+    ///
+    /// assert_eq!(x.count_zeros(), BigUInt::<32>::BITS - 3);
+    /// ```
+    pub fn count_zeros(&self) -> usize {
+        self.internal
+            .iter()
+            .map(|word| word.count_zeros() as usize)
+            .sum()
+    }
+
+    /// Returns the number of significant bits, i.e. the position of the most significant set bit
+    /// plus one. This is `0` for `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0b1011u8) << 200usize;
+    ///
+    /// assert_eq!(x.bits(), 204);
+    /// assert_eq!(<BigUInt<32>>::MIN.bits(), 0);
+    /// ```
+    pub fn bits(&self) -> usize {
+        Self::BITS - self.leading_zeros()
+    }
+
+    /// Returns whether the bit at `index` is set, where bit 0 is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `index` is not smaller than [`BITS`](crate::BigUInt::BITS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0b101u8);
+    ///
+    /// assert!(x.bit(2));
+    /// assert!(!x.bit(1));
+    /// ```
+    pub fn bit(&self, index: usize) -> bool {
+        let (word_index, mask) = bit_position(index, Self::BITS);
+
+        self.internal[word_index] & mask != 0
+    }
+
+    /// Set the bit at `index` to `value`, where bit 0 is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `index` is not smaller than [`BITS`](crate::BigUInt::BITS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let mut x = <BigUInt<32>>::MIN;
+    /// x.set_bit(200, true);
+    ///
+    /// assert_eq!(x, <BigUInt<32>>::from(1u8) << 200usize);
+    /// ```
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        let (word_index, mask) = bit_position(index, Self::BITS);
+
+        if value {
+            self.internal[word_index] |= mask;
+        } else {
+            self.internal[word_index] &= !mask;
+        }
+    }
+
+    /// Invert the bit at `index`, where bit 0 is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `index` is not smaller than [`BITS`](crate::BigUInt::BITS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let mut x = <BigUInt<32>>::from(0b101u8);
+    /// x.flip_bit(0);
+    /// x.flip_bit(1);
+    ///
+    /// assert_eq!(x, BigUInt::from(0b110u8));
+    /// ```
+    pub fn flip_bit(&mut self, index: usize) {
+        let (word_index, mask) = bit_position(index, Self::BITS);
+
+        self.internal[word_index] ^= mask;
+    }
+
+    /// Keep only the lowest `k` bits, clearing all others. For `k` of at least
+    /// [`BITS`](crate::BigUInt::BITS) the value is returned unchanged.
+    ///
+    /// Applied to [`MAX`](crate::BigUInt::MAX), this gives a mask of the lowest `k` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0xABCD_u32);
+    ///
+    /// assert_eq!(x.mask_low_bits(8), BigUInt::from(0xCDu8));
+    /// assert_eq!(<BigUInt<32>>::MAX.mask_low_bits(12), BigUInt::from(0xFFFu32));
+    /// ```
+    pub fn mask_low_bits(mut self, k: usize) -> Self {
+        let word_index = k / WORD_BITS;
+
+        if word_index < NUM_WORDS {
+            self.internal[word_index] &= low_mask(k % WORD_BITS);
+            self.internal[word_index + 1..].fill(0);
+        }
+
+        self
+    }
+
+    /// Extract the bits in `range` into the lowest bits of a [`BigUInt`](crate::BigUInt) of `M`
+    /// words.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` does not lie within the bits of `self`, or if it is
+    /// longer than the number of bits in a `BigUInt<M>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0xABCD_u32) << 120usize;
+    ///
+    /// assert_eq!(x.get_bits::<4>(124..132), BigUInt::from(0xBCu8));
+    /// ```
+    pub fn get_bits<const M: usize>(&self, range: impl RangeBounds<usize>) -> BigUInt<M> {
+        let (start, end) = bit_range(range, Self::BITS);
+        let len = end - start;
+
+        if len > BigUInt::<M>::BITS {
+            panic!(
+                "bit range of {} bits does not fit into a BigUInt of {} bits",
+                len,
+                BigUInt::<M>::BITS
+            );
+        }
+
+        let mut internal = [0; M];
+
+        for (i, word) in internal.iter_mut().enumerate() {
+            let offset = i * WORD_BITS;
+            if offset >= len {
+                break;
+            }
+
+            *word = self.word_at_bit(start + offset) & low_mask(len - offset);
+        }
+
+        BigUInt { internal }
+    }
+
+    /// Extract the bits in `range` into the lowest bits of a [`u64`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` does not lie within the bits of `self`, or if it is
+    /// longer than 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(0xABCD_u32) << 120usize;
+    ///
+    /// assert_eq!(x.get_bits_u64(124..132), 0xBC);
+    /// ```
+    pub fn get_bits_u64(&self, range: impl RangeBounds<usize>) -> u64 {
+        let (start, end) = bit_range(range, Self::BITS);
+
+        if end - start > 64 {
+            panic!("bit range of {} bits does not fit into a u64", end - start);
+        }
+
+        let words = self.get_bits::<U64_WORDS>(start..end).internal;
+
+        let value = words.iter().enumerate().fold(0, |value, (i, &word)| {
+            value | (u128::from(word) << (i * WORD_BITS))
+        });
+
+        value as u64
+    }
+
+    /// Replace the bits in `range` by the lowest bits of `value`.
+    ///
+    /// Bits of `value` beyond the length of `range` are ignored, and a `value` with fewer bits
+    /// than `range` is zero-extended.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` does not lie within the bits of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let mut x = <BigUInt<32>>::MIN;
+    /// x.set_bits(124..132, &<BigUInt<4>>::from(0xBCu8));
+    ///
+    /// assert_eq!(x, <BigUInt<32>>::from(0xBCu8) << 124usize);
+    /// ```
+    pub fn set_bits<const M: usize>(&mut self, range: impl RangeBounds<usize>, value: &BigUInt<M>) {
+        let (start, end) = bit_range(range, Self::BITS);
+        let len = end - start;
+
+        let mut offset = 0;
+        while offset < len {
+            let word = value.internal.get(offset / WORD_BITS).copied().unwrap_or(0);
+            self.set_word_at_bit(start + offset, word, low_mask(len - offset));
+
+            offset += WORD_BITS;
+        }
+    }
+
+    /// Replace the bits in `range` by the lowest bits of the [`u64`] `value`.
+    ///
+    /// Bits of `value` beyond the length of `range` are ignored, and for a `range` longer than 64
+    /// bits `value` is zero-extended.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` does not lie within the bits of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let mut x = <BigUInt<32>>::MAX;
+    /// x.set_bits_u64(4..12, 0);
+    ///
+    /// assert_eq!(x.get_bits_u64(..16), 0xF00F);
+    /// ```
+    pub fn set_bits_u64(&mut self, range: impl RangeBounds<usize>, value: u64) {
+        let mut words = [0; U64_WORDS];
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = (value >> (i * WORD_BITS)) as Word;
+        }
+
+        self.set_bits(range, &BigUInt { internal: words });
+    }
+}
+
+#[test]
+fn get_bits() {
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+    let x = <BigUInt<32>>::from(value) << 61usize;
+
+    for start in (0..128).step_by(7) {
+        for len in 0..=64 {
+            let expected = (value >> start) as u64 & u64::MAX.checked_shr(64 - len).unwrap_or(0);
+
+            assert_eq!(
+                x.get_bits_u64(61 + start..61 + start + len as usize),
+                expected
+            );
+            assert_eq!(
+                x.get_bits::<16>(61 + start..61 + start + len as usize),
+                <BigUInt<16>>::from(expected)
+            );
+        }
+    }
+
+    assert_eq!(x.get_bits::<32>(..), x);
+    assert_eq!(x.get_bits::<16>(61..=188), <BigUInt<16>>::from(value));
+    assert_eq!(x.get_bits_u64(..61), 0);
+}
+
+#[test]
+fn set_bits() {
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+
+    for start in (0..128).step_by(7) {
+        for len in 0..=64 {
+            let mask = u128::from(u64::MAX.checked_shr(64 - len).unwrap_or(0)) << start;
+
+            let mut x = <BigUInt<32>>::MIN;
+            x.set_bits_u64(start..start + len as usize, (value >> start) as u64);
+            assert_eq!(x, <BigUInt<32>>::from(value & mask));
+
+            let mut x = <BigUInt<32>>::MAX;
+            x.set_bits(
+                start..start + len as usize,
+                &<BigUInt<16>>::from(value >> start),
+            );
+
+            // The range may reach beyond the bits of `value`, which are zero-extended
+            let mask = <BigUInt<32>>::from(u64::MAX.checked_shr(64 - len).unwrap_or(0)) << start;
+            assert_eq!(
+                x,
+                (BigUInt::MAX ^ mask) | (<BigUInt<32>>::from(value) & mask)
+            );
+        }
+    }
+
+    // Bits of the value beyond the range are ignored
+    let mut x = <BigUInt<16>>::MIN;
+    x.set_bits(8..16, &<BigUInt<16>>::MAX);
+    assert_eq!(x, BigUInt::from(0xFF00u32));
+
+    let mut x = <BigUInt<16>>::MAX;
+    x.set_bits_u64(.., 0);
+    assert_eq!(x, BigUInt::MIN);
+}
+
+#[test]
+fn bit_counting() {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    for _ in 0..1000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let value = u128::from(state) << 64 | u128::from(state.rotate_left(29));
+        // Clear a random range of bits, to also test runs spanning multiple words
+        let value = value & !(u128::MAX >> (state % 128) << (state >> 57));
+        if value == 0 {
+            continue;
+        }
+        let x = <BigUInt<32>>::from(value) << 61usize;
+
+        // The 128-bit value occupies bits 61..189 of the 256 bits of `x`
+        let high = BigUInt::<32>::BITS - 189;
+
+        assert_eq!(x.leading_zeros(), high + value.leading_zeros() as usize);
+        assert_eq!(x.trailing_zeros(), 61 + value.trailing_zeros() as usize);
+        assert_eq!(x.count_ones(), value.count_ones() as usize);
+        assert_eq!(x.count_zeros(), high + 61 + value.count_zeros() as usize);
+        assert_eq!(x.bits(), 189 - value.leading_zeros() as usize);
+
+        let x = !x;
+        assert_eq!(x.leading_ones(), high + value.leading_zeros() as usize);
+        assert_eq!(x.trailing_ones(), 61 + value.trailing_zeros() as usize);
+    }
+
+    assert_eq!(<BigUInt<32>>::MIN.leading_zeros(), BigUInt::<32>::BITS);
+    assert_eq!(<BigUInt<32>>::MIN.trailing_zeros(), BigUInt::<32>::BITS);
+    assert_eq!(<BigUInt<32>>::MIN.count_ones(), 0);
+    assert_eq!(<BigUInt<32>>::MIN.bits(), 0);
+    assert_eq!(<BigUInt<32>>::MAX.leading_ones(), BigUInt::<32>::BITS);
+    assert_eq!(<BigUInt<32>>::MAX.trailing_ones(), BigUInt::<32>::BITS);
+    assert_eq!(<BigUInt<32>>::MAX.count_zeros(), 0);
+    assert_eq!(<BigUInt<32>>::MAX.bits(), BigUInt::<32>::BITS);
+}
+
+#[test]
+fn single_bits() {
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+    let x = <BigUInt<32>>::from(value) << 61usize;
+
+    for i in 0..BigUInt::<32>::BITS {
+        let expected = (61..61 + 128).contains(&i) && (value >> (i - 61)) & 1 == 1;
+        assert_eq!(x.bit(i), expected);
+
+        let mut y = x;
+        y.flip_bit(i);
+        assert_eq!(y, x ^ (<BigUInt<32>>::from(1u8) << i));
+        assert_eq!(y.bit(i), !expected);
+
+        y.set_bit(i, true);
+        assert_eq!(y, x | (<BigUInt<32>>::from(1u8) << i));
+        y.set_bit(i, false);
+        assert_eq!(y, x & !(<BigUInt<32>>::from(1u8) << i));
+    }
+}
+
+#[test]
+fn mask_low_bits() {
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+    let x = <BigUInt<16>>::from(value);
+
+    for k in 0..128 {
+        assert_eq!(
+            x.mask_low_bits(k),
+            <BigUInt<16>>::from(value & ((1 << k) - 1))
+        );
+    }
+
+    assert_eq!(x.mask_low_bits(128), x);
+    assert_eq!(x.mask_low_bits(usize::MAX), x);
+    assert_eq!(<BigUInt<16>>::MAX.mask_low_bits(0), BigUInt::MIN);
+}
+
+#[test]
+#[should_panic(expected = "bit index")]
+fn bit_out_of_range() {
+    let mut x = <BigUInt<16>>::MIN;
+    x.set_bit(BigUInt::<16>::BITS, true);
+}
+
+#[test]
+#[should_panic(expected = "out of range for a BigUInt of")]
+fn bits_out_of_range() {
+    <BigUInt<16>>::MIN.get_bits_u64(100..=BigUInt::<16>::BITS);
+}
+
+#[test]
+#[should_panic(expected = "bit range out of range for a BigUInt of")]
+fn bits_overflowing_range() {
+    <BigUInt<16>>::MIN.get_bits_u64(..=usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "bit range of 65 bits does not fit into a u64")]
+fn bits_too_long() {
+    <BigUInt<16>>::MIN.get_bits_u64(0..65);
+}
@@ -1,57 +1,159 @@
-use crate::BigUInt;
-use core::ops::{BitAnd, BitOr, BitXor, BitOrAssign, BitAndAssign, BitXorAssign};
+use crate::{u128_to_words, BigUInt};
+use core::ops::{BitAnd, BitOr, BitXor, BitOrAssign, BitAndAssign, BitXorAssign, Not};
 
-impl<const NUM_WORDS: usize> BitAnd<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn bitand(self, rhs: Self) -> Self::Output {
-        let mut internal = [0; NUM_WORDS];
+impl<const NUM_WORDS: usize, const M: usize> BitAndAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn bitand_assign(&mut self, rhs: &BigUInt<M>) {
+        // A shorter `rhs` is zero-extended
+        let rhs = rhs.internal.iter().chain(core::iter::repeat(&0));
 
-        for i in 0..NUM_WORDS {
-            internal[i] = self.internal[i] & rhs.internal[i];
+        for (x, y) in self.internal.iter_mut().zip(rhs) {
+            *x &= y;
         }
-
-        BigUInt { internal }
     }
 }
-impl<const NUM_WORDS: usize> BitOr<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn bitor(self, rhs: Self) -> Self::Output {
-        let mut internal = [0; NUM_WORDS];
+impl<const NUM_WORDS: usize, const M: usize> BitOrAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn bitor_assign(&mut self, rhs: &BigUInt<M>) {
+        // A shorter `rhs` is zero-extended
+        let rhs = rhs.internal.iter().chain(core::iter::repeat(&0));
 
-        for i in 0..NUM_WORDS {
-            internal[i] = self.internal[i] | rhs.internal[i];
+        for (x, y) in self.internal.iter_mut().zip(rhs) {
+            *x |= y;
         }
-
-        BigUInt { internal }
     }
 }
-impl<const NUM_WORDS: usize> BitXor<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        let mut internal = [0; NUM_WORDS];
+impl<const NUM_WORDS: usize, const M: usize> BitXorAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn bitxor_assign(&mut self, rhs: &BigUInt<M>) {
+        // A shorter `rhs` is zero-extended
+        let rhs = rhs.internal.iter().chain(core::iter::repeat(&0));
 
-        for i in 0..NUM_WORDS {
-            internal[i] = self.internal[i] ^ rhs.internal[i];
+        for (x, y) in self.internal.iter_mut().zip(rhs) {
+            *x ^= y;
         }
+    }
+}
 
-        BigUInt { internal }
+impl<const NUM_WORDS: usize, const M: usize> BitAnd<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn bitand(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut result = *self;
+        result &= rhs;
+        result
+    }
+}
+impl<const NUM_WORDS: usize, const M: usize> BitOr<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn bitor(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut result = *self;
+        result |= rhs;
+        result
+    }
+}
+impl<const NUM_WORDS: usize, const M: usize> BitXor<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn bitxor(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut result = *self;
+        result ^= rhs;
+        result
     }
 }
 
-impl<const NUM_WORDS: usize> BitOrAssign<Self> for BigUInt<NUM_WORDS> {
-    fn bitor_assign(&mut self, rhs: Self) {
-        *self = self.clone() | rhs;
+forward_ref_binop!(BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_ref_binop!(BitOr, bitor, BitOrAssign, bitor_assign);
+forward_ref_binop!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<const NUM_WORDS: usize> Not for BigUInt<NUM_WORDS> {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for word in self.internal.iter_mut() {
+            *word = !*word;
+        }
+
+        self
     }
 }
-impl<const NUM_WORDS: usize> BitAndAssign<Self> for BigUInt<NUM_WORDS> {
-    fn bitand_assign(&mut self, rhs: Self) {
-        *self = self.clone() & rhs;
+impl<const NUM_WORDS: usize> Not for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn not(self) -> Self::Output {
+        !*self
     }
 }
-impl<const NUM_WORDS: usize> BitXorAssign<Self> for BigUInt<NUM_WORDS> {
-    fn bitxor_assign(&mut self, rhs: Self) {
-        *self = self.clone() ^ rhs;
+
+macro_rules! bitwise_primitive {
+    ($($utype:ty),*) => {$(
+        impl<const NUM_WORDS: usize> BitAnd<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn bitand(mut self, rhs: $utype) -> Self::Output {
+                self &= rhs;
+                self
+            }
+        }
+        impl<const NUM_WORDS: usize> BitOr<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn bitor(mut self, rhs: $utype) -> Self::Output {
+                self |= rhs;
+                self
+            }
+        }
+        impl<const NUM_WORDS: usize> BitXor<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn bitxor(mut self, rhs: $utype) -> Self::Output {
+                self ^= rhs;
+                self
+            }
+        }
+
+        impl<const NUM_WORDS: usize> BitAndAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn bitand_assign(&mut self, rhs: $utype) {
+                *self &= &BigUInt {
+                    internal: u128_to_words(rhs as u128),
+                };
+            }
+        }
+        impl<const NUM_WORDS: usize> BitOrAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn bitor_assign(&mut self, rhs: $utype) {
+                *self |= &BigUInt {
+                    internal: u128_to_words(rhs as u128),
+                };
+            }
+        }
+        impl<const NUM_WORDS: usize> BitXorAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn bitxor_assign(&mut self, rhs: $utype) {
+                *self ^= &BigUInt {
+                    internal: u128_to_words(rhs as u128),
+                };
+            }
+        }
+    )*};
+}
+
+bitwise_primitive!(u8, u16, u32, u64, u128);
+
+#[test]
+fn bitwise_primitive() {
+    for x in 65500..65600u32 {
+        for y in 200..300u32 {
+            assert_eq!(<BigUInt<128>>::from(x) & y, <BigUInt<128>>::from(x & y));
+            assert_eq!(
+                <BigUInt<128>>::from(x) | y as u16,
+                <BigUInt<128>>::from(x | y)
+            );
+            assert_eq!(
+                <BigUInt<128>>::from(x) ^ u128::from(y) << 64,
+                <BigUInt<128>>::from(u128::from(x) ^ u128::from(y) << 64)
+            );
+        }
     }
+
+    // Bits which do not fit are discarded
+    assert_eq!(<BigUInt<1>>::MIN | u128::MAX, BigUInt::MAX);
+    // While the words above the primitive are cleared
+    assert_eq!(<BigUInt<128>>::MAX & 0xFFu8, BigUInt::from(0xFFu32));
+
+    let mut x = <BigUInt<16>>::from(0b1100u32);
+    x &= 0b0110u8;
+    x |= 0b0001u64;
+    x ^= 0b1111u128;
+    assert_eq!(x, BigUInt::from(0b1010u32));
 }
 
 #[test]
@@ -173,3 +275,49 @@ fn and() {
         }
     }
 }
+
+#[test]
+fn not() {
+    assert_eq!(!BigUInt::MIN, BigUInt::<128>::MAX);
+    assert_eq!(!BigUInt::MAX, BigUInt::<128>::MIN);
+
+    for x in 65500..65600u128 {
+        let y = <BigUInt<16>>::from(x);
+
+        assert_eq!((!y).mask_low_bits(128), BigUInt::from(!x));
+        assert_eq!(!&y, y ^ BigUInt::MAX);
+        assert_eq!(!!y, y);
+    }
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn bitwise_ref() {
+    let x = <BigUInt<16>>::from(0b1100u32);
+    let y = <BigUInt<16>>::from(0b1010u32);
+
+    assert_eq!(&x & &y, BigUInt::from(0b1000u32));
+    assert_eq!(&x | y, BigUInt::from(0b1110u32));
+    assert_eq!(x ^ &y, BigUInt::from(0b0110u32));
+
+    let mut z = x;
+    z &= &y;
+    z |= &x;
+    z ^= &y;
+    assert_eq!(z, BigUInt::from(0b0110u32));
+}
+
+#[test]
+fn bitwise_mixed_width() {
+    let x = <BigUInt<64>>::MAX;
+    let y = <BigUInt<32>>::from(0b1010u32);
+
+    assert_eq!(x & &y, &y);
+    assert_eq!(x | &y, BigUInt::MAX);
+    assert_eq!(x ^ &y, BigUInt::MAX ^ <BigUInt<64>>::from(0b1010u32));
+
+    // Words beyond the width of `self` are discarded
+    let mut z = <BigUInt<16>>::MIN;
+    z |= &<BigUInt<32>>::MAX;
+    assert_eq!(z, BigUInt::MAX);
+}
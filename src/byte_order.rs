@@ -0,0 +1,152 @@
+use crate::BigUInt;
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Reverse the byte order of the whole number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::from(0x1234u16);
+    ///
+    /// assert_eq!(x.swap_bytes(), <BigUInt<16>>::from(0x3412u16) << (BigUInt::<16>::BITS - 16));
+    /// ```
+    pub fn swap_bytes(mut self) -> Self {
+        self.internal.reverse();
+
+        for word in self.internal.iter_mut() {
+            *word = word.swap_bytes();
+        }
+
+        self
+    }
+
+    /// Reverse the order of the bits of the whole number, so the least significant bit becomes
+    /// the most significant bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::from(0b1011u8);
+    ///
+    /// assert_eq!(x.reverse_bits(), <BigUInt<16>>::from(0b1101u8) << (BigUInt::<16>::BITS - 4));
+    /// ```
+    pub fn reverse_bits(mut self) -> Self {
+        self.internal.reverse();
+
+        for word in self.internal.iter_mut() {
+            *word = word.reverse_bits();
+        }
+
+        self
+    }
+
+    /// Convert `x` from big endian to the target's endianness.
+    ///
+    /// This is the inverse of [`BigUInt::to_be`].
+    pub fn from_be(x: Self) -> Self {
+        x.to_be()
+    }
+
+    /// Convert `x` from little endian to the target's endianness.
+    ///
+    /// This is the inverse of [`BigUInt::to_le`].
+    pub fn from_le(x: Self) -> Self {
+        x.to_le()
+    }
+
+    /// Convert `self` to big endian from the target's endianness.
+    ///
+    /// The words are always stored least significant first, so on big endian only the word
+    /// order is reversed. On little endian all the bytes are swapped.
+    pub fn to_be(mut self) -> Self {
+        if cfg!(target_endian = "big") {
+            self.internal.reverse();
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    /// Convert `self` to little endian from the target's endianness.
+    ///
+    /// On little endian this is a no-op. On big endian the bytes within each word are swapped,
+    /// as the words themselves are already stored least significant first.
+    pub fn to_le(mut self) -> Self {
+        if cfg!(target_endian = "big") {
+            for word in self.internal.iter_mut() {
+                *word = word.swap_bytes();
+            }
+        }
+
+        self
+    }
+}
+
+#[test]
+fn swap_bytes() {
+    use crate::WORD_BITS;
+
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+
+    for x in [value, value.rotate_left(29), 0, u128::MAX, 1] {
+        let y = <BigUInt<{ 128 / WORD_BITS }>>::from(x);
+
+        assert_eq!(y.swap_bytes(), BigUInt::from(x.swap_bytes()));
+        assert_eq!(y.swap_bytes().swap_bytes(), y);
+    }
+
+    let x = <BigUInt<32>>::from(value);
+    assert_eq!(
+        x.swap_bytes(),
+        <BigUInt<32>>::from(value.swap_bytes()) << (BigUInt::<32>::BITS - 128)
+    );
+}
+
+#[test]
+fn reverse_bits() {
+    use crate::WORD_BITS;
+
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+
+    for x in [value, value.rotate_left(29), 0, u128::MAX, 1] {
+        let y = <BigUInt<{ 128 / WORD_BITS }>>::from(x);
+
+        assert_eq!(y.reverse_bits(), BigUInt::from(x.reverse_bits()));
+        assert_eq!(y.reverse_bits().reverse_bits(), y);
+    }
+
+    let x = <BigUInt<32>>::from(value);
+    assert_eq!(
+        x.reverse_bits(),
+        <BigUInt<32>>::from(value.reverse_bits()) << (BigUInt::<32>::BITS - 128)
+    );
+}
+
+#[test]
+fn endian_conversions() {
+    use crate::{U128_WORDS, WORD_BYTES};
+
+    // The bytes of `internal` in the order they are laid out in memory
+    fn memory_bytes(x: BigUInt<U128_WORDS>) -> [u8; 16] {
+        let mut bytes = [0; 16];
+
+        for (chunk, word) in bytes.chunks_mut(WORD_BYTES).zip(x.internal.iter()) {
+            chunk.copy_from_slice(&word.to_ne_bytes());
+        }
+
+        bytes
+    }
+
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+    let x = <BigUInt<U128_WORDS>>::from(value);
+
+    assert_eq!(memory_bytes(x.to_be()), value.to_be_bytes());
+    assert_eq!(memory_bytes(x.to_le()), value.to_le_bytes());
+
+    assert_eq!(BigUInt::from_be(x.to_be()), x);
+    assert_eq!(BigUInt::from_le(x.to_le()), x);
+}
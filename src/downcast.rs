@@ -9,7 +9,8 @@ macro_rules! downcast_type_bigger_than_word {
             /// If value exceeds maximum value of type to which cast was attempted, the return
             /// value will be `None`.
             pub fn $fn_name(self) -> Option<$type> {
-                if !(self.clone() >> (core::mem::size_of::<$type>() * 8)).is_zero() {
+                let overflow = self.checked_shr(core::mem::size_of::<$type>() * 8);
+                if overflow.map_or(false, |overflow| !overflow.is_zero()) {
                     return None;
                 }
                 const WORDS_NEEDED: usize = core::mem::size_of::<$type>() / crate::WORD_BYTES;
@@ -34,7 +35,8 @@ macro_rules! downcast_type_smaller_eq_than_word {
             /// If value exceeds maximum value of type to which cast was attempted, the return
             /// value will be `None`.
             pub fn $fn_name(self) -> Option<$type> {
-                if !(self.clone() >> (core::mem::size_of::<$type>() * 8)).is_zero() {
+                let overflow = self.checked_shr(core::mem::size_of::<$type>() * 8);
+                if overflow.map_or(false, |overflow| !overflow.is_zero()) {
                     return None;
                 }
 
@@ -76,6 +78,15 @@ macro_rules! to_test {
                 <BigUInt<20>>::from(<$type>::MAX).increase().$fn_name(),
                 None
             );
+
+            // Types exactly as wide as the BigUInt
+            const EXACT_WORDS: usize = core::mem::size_of::<$type>() / crate::WORD_BYTES;
+            if EXACT_WORDS > 0 {
+                assert_eq!(
+                    <BigUInt<EXACT_WORDS>>::from(<$type>::MAX).$fn_name(),
+                    Some(<$type>::MAX)
+                );
+            }
         }
     };
 }
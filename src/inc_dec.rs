@@ -5,7 +5,13 @@ impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
     ///
     /// Will overflow back to [`MIN`](crate::BigUInt::MIN), when ran on the
     /// [`MAX`](crate::BigUInt::MIN) value.
-    pub fn increase(mut self) -> Self {
+    pub fn increase(self) -> Self {
+        self.overflowing_increase().0
+    }
+
+    /// Increase a [`BigUInt`](crate::BigUInt) by 1, returning the result along with whether an
+    /// overflow occurred.
+    pub fn overflowing_increase(mut self) -> (Self, bool) {
         // Go until a byte has been found that 1 can be added to.
         for i in 0..NUM_WORDS {
             let byte = self.internal[i];
@@ -13,20 +19,26 @@ impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
             // Check whether the value can be added to.
             if byte < Word::MAX {
                 self.internal[i] = byte + 1;
-                break;
+                return (self, false);
             } else {
                 self.internal[i] = 0;
             }
         }
 
-        self
+        (self, true)
     }
 
     /// Decrease a [`BigUInt`](crate::BigUInt) by 1.
     ///
     /// Will underflow back to [`MAX`](crate::BigUInt::MAX), when ran on the
     /// [`MIN`](crate::BigUInt::MAX) value.
-    pub fn decrease(mut self) -> Self {
+    pub fn decrease(self) -> Self {
+        self.overflowing_decrease().0
+    }
+
+    /// Decrease a [`BigUInt`](crate::BigUInt) by 1, returning the result along with whether an
+    /// underflow occurred.
+    pub fn overflowing_decrease(mut self) -> (Self, bool) {
         // Go until a byte has been found that 1 can be subtracted from.
         for i in 0..NUM_WORDS {
             let byte = self.internal[i];
@@ -34,13 +46,13 @@ impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
             // Check whether the value can be subtracted from.
             if byte > 0 {
                 self.internal[i] = byte - 1;
-                break;
+                return (self, false);
             } else {
                 self.internal[i] = Word::MAX;
             }
         }
 
-        self
+        (self, true)
     }
 }
 
@@ -104,3 +116,26 @@ fn decrease() {
         assert_eq!(<BigUInt<128>>::from(x).decrease(), <BigUInt<128>>::from(x - 1));
     }
 }
+
+#[test]
+fn overflowing_increase() {
+    assert_eq!(
+        <BigUInt<16>>::from(255u32).overflowing_increase(),
+        (BigUInt::from(256u32), false)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.overflowing_increase(),
+        (BigUInt::MIN, true)
+    );
+}
+#[test]
+fn overflowing_decrease() {
+    assert_eq!(
+        <BigUInt<16>>::from(256u32).overflowing_decrease(),
+        (BigUInt::from(255u32), false)
+    );
+    assert_eq!(
+        BigUInt::<16>::MIN.overflowing_decrease(),
+        (BigUInt::MAX, true)
+    );
+}
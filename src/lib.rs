@@ -32,6 +32,22 @@
 //! all the normal arithmetic operators for this `struct`. Along with some other helpful functions,
 //! most of them packed under their own features.
 //!
+//! The arithmetic and bitwise operators also take the primitive unsigned integers as their
+//! right-hand side, so `x * 10u32` works without any conversion or feature. Since there is no
+//! single integer type to infer, a literal right-hand side needs its suffix, as in `x << 4usize`.
+//! Both operands may also be references, so `&x * &y` does not need to clone either of them. A
+//! right-hand side taken by reference may even be of a different width, as in `x + &y` or
+//! `x < &y` for a `BigUInt<64>` and a `BigUInt<32>`, where the narrower operand is zero-extended
+//! and the result has the width of the left-hand side.
+//!
+//! The assigning operators like `+=` and `<<=` work in place, without any temporary copies of the
+//! number on the stack. The one exception is `*=` on large numbers, where Karatsuba multiplication
+//! needs temporary buffers of six times the size of the number.
+//!
+//! Just like with the primitive integers, the `+`, `-` and `*` operators panic on overflow when
+//! `debug_assertions` are enabled and wrap around otherwise. When wrapping around is intended, use
+//! the explicit `wrapping_*` methods instead.
+//!
 //! # Features
 //!
 //! ## Creation from standard unsigned integers
@@ -69,7 +85,7 @@
 //! use tiny_big_uint::BigUInt;
 //!
 //! let a = BigUInt::from_be_bytes([0x00, 0x76, 0x54, 0x32, 0x10]);
-//! assert_eq!(a << 4, BigUInt::from_be_bytes([0x07, 0x65, 0x43, 0x21, 0x00]));
+//! assert_eq!(a << 4usize, BigUInt::from_be_bytes([0x07, 0x65, 0x43, 0x21, 0x00]));
 //! ```
 //!
 //! To use this, just add the following to your `Cargo.toml`.
@@ -107,8 +123,42 @@
 
 #![feature(test)]
 
+/// Implements the by-value and mixed reference variants of a binary operator in terms of its
+/// `&BigUInt<N> op &BigUInt<M>` implementation, and the by-value assigning operator in terms of
+/// the in-place `BigUInt<N> op= &BigUInt<M>` implementation.
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl<const NUM_WORDS: usize> $imp<BigUInt<NUM_WORDS>> for BigUInt<NUM_WORDS> {
+            type Output = BigUInt<NUM_WORDS>;
+            fn $method(self, rhs: BigUInt<NUM_WORDS>) -> Self::Output {
+                $imp::$method(&self, &rhs)
+            }
+        }
+        impl<const NUM_WORDS: usize, const M: usize> $imp<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+            type Output = BigUInt<NUM_WORDS>;
+            fn $method(self, rhs: &BigUInt<M>) -> Self::Output {
+                $imp::$method(&self, rhs)
+            }
+        }
+        impl<const NUM_WORDS: usize> $imp<BigUInt<NUM_WORDS>> for &BigUInt<NUM_WORDS> {
+            type Output = BigUInt<NUM_WORDS>;
+            fn $method(self, rhs: BigUInt<NUM_WORDS>) -> Self::Output {
+                $imp::$method(self, &rhs)
+            }
+        }
+
+        impl<const NUM_WORDS: usize> $imp_assign<BigUInt<NUM_WORDS>> for BigUInt<NUM_WORDS> {
+            fn $method_assign(&mut self, rhs: BigUInt<NUM_WORDS>) {
+                $imp_assign::$method_assign(self, &rhs);
+            }
+        }
+    };
+}
+
 mod add_sub;
+mod bits;
 mod bitwise_ops;
+mod byte_order;
 #[cfg(any(feature = "bytearrays", test))]
 mod bytearrays;
 #[cfg(any(feature = "upcasting", test))]
@@ -117,6 +167,8 @@ mod upcast;
 mod downcast;
 mod inc_dec;
 mod mul_div_rem;
+mod pow;
+mod resize;
 mod shift;
 
 use core::mem::size_of;
@@ -136,10 +188,33 @@ pub(crate) type Word = u32;
 #[cfg(feature = "64bit")]
 pub(crate) type Word = u64;
 
+/// An unsigned integer twice the width of a [`Word`], used to hold intermediate products.
+#[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
+pub(crate) type DoubleWord = u16;
+#[cfg(feature = "16bit")]
+pub(crate) type DoubleWord = u32;
+#[cfg(feature = "32bit")]
+pub(crate) type DoubleWord = u64;
+#[cfg(feature = "64bit")]
+pub(crate) type DoubleWord = u128;
+
 pub(crate) const WORD_BYTES: usize = size_of::<Word>();
 pub(crate) const WORD_BITS: usize = WORD_BYTES * 8;
 
-#[cfg(any(feature = "downcasting", feature = "bytearrays", test))]
+/// The amount of words needed to hold a [`u128`].
+pub(crate) const U128_WORDS: usize = 128 / WORD_BITS;
+
+/// Split `num` into its words, starting at the least significant one.
+pub(crate) fn u128_to_words(num: u128) -> [Word; U128_WORDS] {
+    let mut words = [0; U128_WORDS];
+
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = (num >> (i * WORD_BITS)) as Word;
+    }
+
+    words
+}
+
 pub(crate) const fn min(a: usize, b: usize) -> usize {
     if a < b {
         a
@@ -160,9 +235,9 @@ pub(crate) const fn min(a: usize, b: usize) -> usize {
 ///
 /// let a = BigUInt::from_be_words([0x00, 0x76, 0x54, 0x32, 0x10]);
 ///
-/// assert_eq!(a << 4, BigUInt::from_be_words([0x07, 0x65, 0x43, 0x21, 0x00]));
+/// assert_eq!(a << 4usize, BigUInt::from_be_words([0x07, 0x65, 0x43, 0x21, 0x00]));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BigUInt<const NUM_WORDS: usize> {
     /// Little-endian representation of bytes of the bytes of a bigger unsigned integer
     internal: [Word; NUM_WORDS],
@@ -179,6 +254,9 @@ impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
         internal: [Word::MAX; NUM_WORDS],
     };
 
+    /// The size of a BigUInt in bits
+    pub const BITS: usize = NUM_WORDS * WORD_BITS;
+
     /// Create a BigUInt from words put in Little-Endian
     pub fn from_le_words(words: [Word; NUM_WORDS]) -> Self {
         BigUInt { internal: words }
@@ -215,6 +293,37 @@ impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
 
 use core::cmp::{Ord, Ordering};
 
+/// Compare two little-endian word slices of possibly different lengths, treating the missing
+/// words of the shorter one as zero.
+pub(crate) fn cmp_words(lhs: &[Word], rhs: &[Word]) -> Ordering {
+    for i in (0..lhs.len().max(rhs.len())).rev() {
+        let x = lhs.get(i).copied().unwrap_or(0);
+        let y = rhs.get(i).copied().unwrap_or(0);
+
+        match x.cmp(&y) {
+            Ordering::Equal => continue,
+            x => return x,
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Compares against a [`BigUInt`](crate::BigUInt) of any width, as if the narrower of the two
+/// was zero-extended.
+impl<const NUM_WORDS: usize, const M: usize> PartialEq<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn eq(&self, other: &&BigUInt<M>) -> bool {
+        cmp_words(&self.internal, &other.internal) == Ordering::Equal
+    }
+}
+/// Compares against a [`BigUInt`](crate::BigUInt) of any width, as if the narrower of the two
+/// was zero-extended.
+impl<const NUM_WORDS: usize, const M: usize> PartialOrd<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn partial_cmp(&self, other: &&BigUInt<M>) -> Option<Ordering> {
+        Some(cmp_words(&self.internal, &other.internal))
+    }
+}
+
 impl<const NUM_WORDS: usize> PartialOrd for BigUInt<NUM_WORDS> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -222,14 +331,7 @@ impl<const NUM_WORDS: usize> PartialOrd for BigUInt<NUM_WORDS> {
 }
 impl<const NUM_WORDS: usize> Ord for BigUInt<NUM_WORDS> {
     fn cmp(&self, other: &Self) -> Ordering {
-        for i in (0..NUM_WORDS).rev() {
-            match self.internal[i].cmp(&other.internal[i]) {
-                Ordering::Equal => continue,
-                x => return x,
-            }
-        }
-
-        Ordering::Equal
+        cmp_words(&self.internal, &other.internal)
     }
 }
 
@@ -259,6 +361,25 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn mixed_width_cmp() {
+        for x in 200..300u32 {
+            for y in 200..300u32 {
+                let a = <BigUInt<16>>::from(x);
+                let b = <BigUInt<32>>::from(y);
+
+                assert_eq!(x == y, a == &b);
+                assert_eq!(x == y, b == &a);
+                assert_eq!(x < y, a < &b);
+                assert_eq!(x > y, b < &a);
+            }
+        }
+
+        // The words beyond the narrower width decide
+        assert!(<BigUInt<16>>::MAX < &(<BigUInt<32>>::from(1u32) << BigUInt::<16>::BITS));
+        assert!(<BigUInt<16>>::MAX != &<BigUInt<32>>::MAX);
+    }
+
     #[test]
     fn bigint_is_zero() {
         assert!(<BigUInt<128>>::MIN.is_zero());
@@ -1,72 +1,940 @@
-use crate::BigUInt;
+use crate::add_sub::{borrowing_sub_words, carrying_add_words};
+use crate::{u128_to_words, BigUInt, DoubleWord, Word, WORD_BITS};
+use core::cmp::Ordering;
 use core::ops::{Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
 
-impl<const NUM_WORDS: usize> Mul<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn mul(self, mut rhs: Self) -> Self::Output {
-        let mut big_int = BigUInt::MIN;
-        let mut shifted = 0;
+/// Multiply `rhs` by the single word `x` and add the result onto `out`.
+///
+/// Both slices are expected to be of equal length. Returns the carry out of the most significant
+/// word.
+pub(crate) fn mul_add_word(out: &mut [Word], x: Word, rhs: &[Word], carry: Word) -> Word {
+    let x = DoubleWord::from(x);
+    let mut carry = DoubleWord::from(carry);
 
-        while !rhs.is_zero() {
-            if !rhs.is_even() {
-                big_int += self.clone() << shifted;
+    // `x * y + out + carry` is at most `(2^WORD_BITS)^2 - 1`, so it always fits.
+    for (o, &y) in out.iter_mut().zip(rhs) {
+        let t = x * DoubleWord::from(y) + DoubleWord::from(*o) + carry;
+
+        *o = t as Word;
+        carry = t >> WORD_BITS;
+    }
+
+    carry as Word
+}
+
+/// Schoolbook multiplication of `lhs` and `rhs`, accumulated into `out`.
+///
+/// Every word of the product is added onto the word already present in `out`. Anything that
+/// does not fit into `out.len()` words is discarded.
+pub(crate) fn mul_into(out: &mut [Word], lhs: &[Word], rhs: &[Word]) {
+    for (i, &x) in lhs.iter().enumerate().take(out.len()) {
+        if x == 0 {
+            continue;
+        }
+
+        let end = crate::min(i + rhs.len(), out.len());
+        let mut carry = mul_add_word(&mut out[i..end], x, &rhs[..end - i], 0);
+
+        // Propagate the leftover carry as far as it fits
+        for o in &mut out[end..] {
+            if carry == 0 {
+                break;
             }
 
-            shifted += 1;
-            rhs >>= 1;
+            let (word, overflow) = o.overflowing_add(carry);
+            *o = word;
+            carry = Word::from(overflow);
+        }
+    }
+}
+
+/// Schoolbook multiplication of `out` by `rhs` in place, truncated to `out.len()` words.
+///
+/// Returns whether any non-zero part of the product was discarded.
+pub(crate) fn mul_assign_words(out: &mut [Word], rhs: &[Word]) -> bool {
+    let rhs = &rhs[..significant_words(rhs)];
+    let mut overflow = false;
+
+    // Going from the most significant word down, every row only adds onto words which already
+    // belong to the product
+    for i in (0..out.len()).rev() {
+        let x = out[i];
+        out[i] = 0;
+
+        if x == 0 {
+            continue;
+        }
+
+        let end = crate::min(i + rhs.len(), out.len());
+        let mut carry = mul_add_word(&mut out[i..end], x, &rhs[..end - i], 0);
+
+        // Propagate the leftover carry as far as it fits
+        for o in &mut out[end..] {
+            if carry == 0 {
+                break;
+            }
+
+            let (word, carry_out) = o.overflowing_add(carry);
+            *o = word;
+            carry = Word::from(carry_out);
+        }
+
+        overflow |= carry != 0 || end - i < rhs.len();
+    }
+
+    overflow
+}
+
+/// The amount of words from which on multiplication switches from schoolbook to Karatsuba.
+///
+/// The crossover depends on the word size, see the `Widening Multiplication` benches: a level of
+/// Karatsuba starts to pay off from about 40 words with 8-bit words, 56 words with 16-bit and
+/// 32-bit words, and 48 words with 64-bit words.
+#[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
+pub(crate) const KARATSUBA_THRESHOLD: usize = 40;
+#[cfg(any(feature = "16bit", feature = "32bit"))]
+pub(crate) const KARATSUBA_THRESHOLD: usize = 56;
+#[cfg(feature = "64bit")]
+pub(crate) const KARATSUBA_THRESHOLD: usize = 48;
+
+/// The amount of words from which on truncated multiplication switches from schoolbook to
+/// Karatsuba.
+///
+/// Karatsuba always calculates the full double-width product, while truncated schoolbook
+/// multiplication only calculates the lower half. So it only pays off for much larger numbers:
+/// from about 192 words with 8-bit words, and from about 512 words with the wider words, see the
+/// `Wrapping Multiplication` benches.
+#[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
+pub(crate) const KARATSUBA_TRUNCATED_THRESHOLD: usize = 192;
+#[cfg(any(feature = "16bit", feature = "32bit", feature = "64bit"))]
+pub(crate) const KARATSUBA_TRUNCATED_THRESHOLD: usize = 512;
+
+/// The amount of words from which on squaring switches from schoolbook to Karatsuba.
+///
+/// Schoolbook squaring only needs about half the word multiplications, so Karatsuba catches up
+/// later than for multiplication: from about 96 words with 8-bit, 32-bit and 64-bit words, and 80
+/// words with 16-bit words, see the `Widening Squaring` benches.
+#[cfg(not(feature = "16bit"))]
+pub(crate) const KARATSUBA_SQUARE_THRESHOLD: usize = 96;
+#[cfg(feature = "16bit")]
+pub(crate) const KARATSUBA_SQUARE_THRESHOLD: usize = 80;
+
+/// The amount of words from which on truncated squaring switches from schoolbook to Karatsuba.
+///
+/// This is from about 384 words with 8-bit words, and from about 1024 words with the wider words,
+/// see the `Wrapping Squaring` benches.
+#[cfg(not(any(feature = "16bit", feature = "32bit", feature = "64bit")))]
+pub(crate) const KARATSUBA_TRUNCATED_SQUARE_THRESHOLD: usize = 384;
+#[cfg(any(feature = "16bit", feature = "32bit", feature = "64bit"))]
+pub(crate) const KARATSUBA_TRUNCATED_SQUARE_THRESHOLD: usize = 1024;
+
+/// Returns the amount of scratch words Karatsuba needs over all its recursion levels for operands
+/// of `n` words. This never exceeds `4 * n`.
+pub(crate) const fn karatsuba_scratch_len(n: usize) -> usize {
+    if n < KARATSUBA_THRESHOLD {
+        return 0;
+    }
+
+    // Every level takes `2m + 1` words for the middle term and `2m` for the product of the
+    // differences, before recursing on `m` words
+    let m = n - n / 2;
+    4 * m + 1 + karatsuba_scratch_len(m)
+}
+
+/// Write `|lhs - rhs|` into `dst`, returning whether `lhs < rhs`.
+///
+/// `dst` has to be as long as `lhs`, while `rhs` may be shorter.
+fn abs_diff_words(dst: &mut [Word], lhs: &[Word], rhs: &[Word]) -> bool {
+    let less = lhs
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, x)| x.cmp(rhs.get(i).unwrap_or(&0)))
+        .find(|ordering| ordering.is_ne())
+        == Some(Ordering::Less);
+
+    if less {
+        dst[..rhs.len()].copy_from_slice(rhs);
+        dst[rhs.len()..].fill(0);
+        borrowing_sub_words(dst, lhs.iter().copied(), false);
+    } else {
+        dst.copy_from_slice(lhs);
+        borrowing_sub_words(dst, rhs.iter().copied(), false);
+    }
+
+    less
+}
+
+/// Karatsuba multiplication of the equally long `lhs` and `rhs`, written into `out`.
+///
+/// `out` has to be twice as long as `lhs`. `scratch` is used for the intermediate values of all
+/// recursion levels and needs at most four times the length of `lhs`, as given by
+/// [`karatsuba_scratch_len`].
+pub(crate) fn karatsuba_into(out: &mut [Word], lhs: &[Word], rhs: &[Word], scratch: &mut [Word]) {
+    let n = lhs.len();
+    debug_assert!(scratch.len() >= karatsuba_scratch_len(n));
+
+    if n < KARATSUBA_THRESHOLD {
+        out.fill(0);
+        mul_into(out, lhs, rhs);
+        return;
+    }
+
+    // Split into `lhs = a1 * B^h + a0` and `rhs = b1 * B^h + b0`
+    let h = n / 2;
+    let m = n - h;
+    let (a0, a1) = lhs.split_at(h);
+    let (b0, b1) = rhs.split_at(h);
+
+    // z0 = a0 * b0 and z2 = a1 * b1 go straight into their place in the output
+    {
+        let (z0, z2) = out.split_at_mut(2 * h);
+        karatsuba_into(z0, a0, b0, scratch);
+        karatsuba_into(z2, a1, b1, scratch);
+    }
+
+    let (mid, rest) = scratch.split_at_mut(2 * m + 1);
+    let (t, rest) = rest.split_at_mut(2 * m);
+
+    // t = |a1 - a0| * |b1 - b0|, which saves us from handling the carries of `a1 + a0`
+    let negative = {
+        let (da, db) = mid.split_at_mut(m);
+        let db = &mut db[..m];
+
+        let negative = abs_diff_words(da, a1, a0) != abs_diff_words(db, b1, b0);
+        karatsuba_into(t, da, db, rest);
+
+        negative
+    };
+
+    // a0 * b1 + a1 * b0 = z0 + z2 - (a1 - a0) * (b1 - b0)
+    mid[..2 * m].copy_from_slice(&out[2 * h..]);
+    mid[2 * m] = 0;
+    carrying_add_words(mid, out[..2 * h].iter().copied(), false);
+    if negative {
+        carrying_add_words(mid, t.iter().copied(), false);
+    } else {
+        borrowing_sub_words(mid, t.iter().copied(), false);
+    }
+
+    carrying_add_words(&mut out[h..], mid.iter().copied(), false);
+}
+
+/// Square `words` into `out`, computing every cross product only once.
+///
+/// Anything that does not fit into `out.len()` words is discarded.
+pub(crate) fn square_into(out: &mut [Word], words: &[Word]) {
+    let n = words.len();
+    let len = out.len();
+
+    out.fill(0);
+
+    // The cross products `words[i] * words[j]` with `i < j`
+    for (i, &x) in words.iter().enumerate() {
+        let start = 2 * i + 1;
+        if start >= len {
+            break;
+        }
+
+        if x == 0 {
+            continue;
         }
 
-        big_int
+        let end = crate::min(i + n, len);
+        let carry = mul_add_word(&mut out[start..end], x, &words[i + 1..], 0);
+
+        if end < len {
+            out[end] = carry;
+        }
+    }
+
+    // Every cross product appears twice in the square
+    let mut carry = 0;
+    for o in out.iter_mut() {
+        let word = *o;
+
+        *o = (word << 1) | carry;
+        carry = word >> (WORD_BITS - 1);
+    }
+
+    // Add the squares on the diagonal
+    let mut carry: DoubleWord = 0;
+    for (i, &x) in words.iter().enumerate() {
+        if 2 * i >= len {
+            break;
+        }
+
+        let square = DoubleWord::from(x) * DoubleWord::from(x);
+
+        let t = DoubleWord::from(out[2 * i]) + (square & DoubleWord::from(Word::MAX)) + carry;
+        out[2 * i] = t as Word;
+        carry = t >> WORD_BITS;
+
+        if 2 * i + 1 < len {
+            let t = DoubleWord::from(out[2 * i + 1]) + (square >> WORD_BITS) + carry;
+            out[2 * i + 1] = t as Word;
+            carry = t >> WORD_BITS;
+        }
     }
 }
-impl<const NUM_WORDS: usize> Div<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn div(mut self, rhs: Self) -> Self::Output {
-        // TLDR: Dividing is a counter of repeated subtraction
-        // Again, I know :(
 
-        let mut big_int = BigUInt::MIN;
+/// Karatsuba squaring of `words`, written into `out`.
+///
+/// `out` has to be twice as long as `words`. `scratch` is used for the intermediate values of all
+/// recursion levels. Squaring does not recurse any deeper than multiplication, so it needs at most
+/// four times the length of `words` as well, as given by [`karatsuba_scratch_len`].
+pub(crate) fn karatsuba_square_into(out: &mut [Word], words: &[Word], scratch: &mut [Word]) {
+    let n = words.len();
+    debug_assert!(scratch.len() >= karatsuba_scratch_len(n));
+
+    if n < KARATSUBA_SQUARE_THRESHOLD {
+        square_into(out, words);
+        return;
+    }
+
+    // Split into `words = a1 * B^h + a0`
+    let h = n / 2;
+    let m = n - h;
+    let (a0, a1) = words.split_at(h);
+
+    {
+        let (z0, z2) = out.split_at_mut(2 * h);
+        karatsuba_square_into(z0, a0, scratch);
+        karatsuba_square_into(z2, a1, scratch);
+    }
+
+    let (mid, rest) = scratch.split_at_mut(2 * m + 1);
+    let (t, rest) = rest.split_at_mut(2 * m);
+
+    // t = (a1 - a0)^2, which is never negative
+    {
+        let da = &mut mid[..m];
+
+        abs_diff_words(da, a1, a0);
+        karatsuba_square_into(t, da, rest);
+    }
+
+    // 2 * a0 * a1 = z0 + z2 - (a1 - a0)^2
+    mid[..2 * m].copy_from_slice(&out[2 * h..]);
+    mid[2 * m] = 0;
+    carrying_add_words(mid, out[..2 * h].iter().copied(), false);
+    borrowing_sub_words(mid, t.iter().copied(), false);
+
+    carrying_add_words(&mut out[h..], mid.iter().copied(), false);
+}
 
-        loop {
-            if rhs > self {
-                break big_int;
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Squares `self`, returning the low and high half of the square.
+    ///
+    /// Since `a * b` and `b * a` only need to be calculated once, this takes roughly half the word
+    /// multiplications of [`widening_mul`](crate::BigUInt::widening_mul).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let (lo, hi) = <BigUInt<4>>::MAX.widening_square();
+    ///
+    /// assert_eq!(lo, BigUInt::from(1u32));
+    /// assert_eq!(hi, BigUInt::MAX.decrease());
+    /// ```
+    pub fn widening_square(self) -> (Self, Self) {
+        // The buffers are kept in whole `NUM_WORDS` chunks, to be able to size them on the stack
+        let mut square = [[0; NUM_WORDS]; 2];
+
+        if NUM_WORDS >= KARATSUBA_SQUARE_THRESHOLD {
+            let mut scratch = [[0; NUM_WORDS]; 4];
+            karatsuba_square_into(
+                square.as_flattened_mut(),
+                &self.internal,
+                scratch.as_flattened_mut(),
+            );
+        } else {
+            square_into(square.as_flattened_mut(), &self.internal);
+        }
+
+        let [lo, hi] = square;
+        (BigUInt { internal: lo }, BigUInt { internal: hi })
+    }
+
+    /// Wrapping squaring. Computes `self * self`, wrapping around at the boundary of the type.
+    pub fn wrapping_square(self) -> Self {
+        if NUM_WORDS >= KARATSUBA_TRUNCATED_SQUARE_THRESHOLD {
+            return self.widening_square().0;
+        }
+
+        let mut internal = [0; NUM_WORDS];
+        square_into(&mut internal, &self.internal);
+
+        BigUInt { internal }
+    }
+
+    /// Squares `self`. This is equal to `self * self`, but takes roughly half the word
+    /// multiplications.
+    ///
+    /// Like the multiplication operator, this panics on overflow when `debug_assertions` are
+    /// enabled and wraps around otherwise.
+    pub fn square(self) -> Self {
+        if cfg!(debug_assertions) {
+            let (lo, hi) = self.widening_square();
+
+            if !hi.is_zero() {
+                panic!("attempt to multiply with overflow");
+            }
+
+            return lo;
+        }
+
+        self.wrapping_square()
+    }
+
+    /// Karatsuba multiplication, returning the low and high half of the product.
+    ///
+    /// Expects `rhs` to be `NUM_WORDS` words long.
+    fn karatsuba_mul(&self, rhs: &[Word]) -> (Self, Self) {
+        // The buffers are kept in whole `NUM_WORDS` chunks, to be able to size them on the stack
+        let mut product = [[0; NUM_WORDS]; 2];
+        let mut scratch = [[0; NUM_WORDS]; 4];
+
+        karatsuba_into(
+            product.as_flattened_mut(),
+            &self.internal,
+            rhs,
+            scratch.as_flattened_mut(),
+        );
+
+        let [lo, hi] = product;
+        (BigUInt { internal: lo }, BigUInt { internal: hi })
+    }
+
+    /// Multiply `self` by `rhs` without losing any precision.
+    ///
+    /// Returns the low and high half of the double-width product, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let (lo, hi) = <BigUInt<4>>::MAX.widening_mul(BigUInt::MAX);
+    ///
+    /// assert_eq!(lo, BigUInt::from(1u32));
+    /// assert_eq!(hi, BigUInt::MAX.decrease());
+    /// ```
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        if NUM_WORDS >= KARATSUBA_THRESHOLD {
+            return self.karatsuba_mul(&rhs.internal);
+        }
+
+        let mut lo = [0; NUM_WORDS];
+        let mut hi = [0; NUM_WORDS];
+
+        for (i, &x) in self.internal.iter().enumerate() {
+            if x == 0 {
+                continue;
             }
 
-            self = self - rhs.clone();
-            big_int = big_int.increase();
+            // Row `i` covers words `i..(i + NUM_WORDS)`, straddling both halves
+            let split = NUM_WORDS - i;
+            let carry = mul_add_word(&mut lo[i..], x, &rhs.internal[..split], 0);
+            hi[i] = mul_add_word(&mut hi[..i], x, &rhs.internal[split..], carry);
+        }
+
+        (BigUInt { internal: lo }, BigUInt { internal: hi })
+    }
+
+    /// Multiply `self` by `rhs` into a [`BigUInt`](crate::BigUInt) of `M` words.
+    ///
+    /// With `M >= 2 * NUM_WORDS` the full product is kept. Otherwise, any words not fitting into
+    /// `M` words are discarded.
+    pub fn widening_mul_into<const M: usize>(self, rhs: Self) -> BigUInt<M> {
+        let mut internal = [0; M];
+
+        if NUM_WORDS >= KARATSUBA_THRESHOLD {
+            let (lo, hi) = self.karatsuba_mul(&rhs.internal);
+            let split = crate::min(M, NUM_WORDS);
+
+            internal[..split].copy_from_slice(&lo.internal[..split]);
+            internal[split..crate::min(M, 2 * NUM_WORDS)]
+                .copy_from_slice(&hi.internal[..crate::min(M, 2 * NUM_WORDS) - split]);
+        } else {
+            mul_into(&mut internal, &self.internal, &rhs.internal);
         }
+
+        BigUInt { internal }
     }
 }
-impl<const NUM_WORDS: usize> Rem<Self> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn rem(mut self, rhs: Self) -> Self::Output {
-        // TLDR: Modulo is repeated subtraction
-        // Once again, I know :(
 
-        loop {
-            if rhs > self {
-                break self;
+/// Returns the amount of words up to and including the most significant non-zero word.
+pub(crate) fn significant_words(words: &[Word]) -> usize {
+    words.iter().rposition(|&word| word != 0).map_or(0, |i| i + 1)
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Divide `self` by `rhs`, returning both the quotient and the remainder.
+    ///
+    /// This is cheaper than calculating `self / rhs` and `self % rhs` separately, since both
+    /// come out of the same long division.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let (q, r) = <BigUInt<16>>::from(23u32).div_rem(BigUInt::from(5u32));
+    ///
+    /// assert_eq!(q, BigUInt::from(4u32));
+    /// assert_eq!(r, BigUInt::from(3u32));
+    /// ```
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        self.div_rem_ref(&rhs)
+    }
+
+    /// Divide `self` by `rhs` by reference, returning both the quotient and the remainder.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    pub fn div_rem_ref(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        let v = &rhs.internal[..significant_words(&rhs.internal)];
+        let mut quotient = *self;
+        let mut remainder = Self::MIN;
+
+        // Dividing by a single word is just a linear pass
+        if v.len() == 1 {
+            remainder.internal[0] = quotient.div_rem_word_assign(v[0]);
+            return (quotient, remainder);
+        }
+
+        let top = quotient.long_div_assign(v);
+        remainder.internal[..v.len()].copy_from_slice(&quotient.internal[..v.len()]);
+        quotient.take_quotient(v.len(), top);
+
+        (quotient, remainder)
+    }
+
+    /// Divide `self` in place by the divisor `v`, which has at least two words and a non-zero
+    /// most significant word.
+    ///
+    /// Afterwards, the lowest `v.len()` words of `self` hold the remainder. The words above them,
+    /// continued by the returned word, hold the quotient.
+    ///
+    /// This is Algorithm D from Knuth's *The Art of Computer Programming, Vol. 2, 4.3.1*, which
+    /// takes `O(n^2)` word operations.
+    fn long_div_assign(&mut self, v: &[Word]) -> Word {
+        const BASE: DoubleWord = 1 << WORD_BITS;
+
+        let un = &mut self.internal;
+
+        let m = significant_words(un);
+        let n = v.len();
+
+        // The dividend is already the remainder
+        if m < n {
+            return 0;
+        }
+
+        // Normalize, so that the most significant bit of the divisor is set
+        let shift = v[n - 1].leading_zeros();
+        let vn = |i: usize| {
+            let lower = if i == 0 { 0 } else { v[i - 1] };
+            (v[i] << shift) | lower.checked_shr(WORD_BITS as u32 - shift).unwrap_or(0)
+        };
+
+        // The normalized dividend may need one more word than we have available
+        let mut un_overflow: Word = 0;
+
+        if shift != 0 {
+            let shift = shift as usize;
+
+            un_overflow = un[NUM_WORDS - 1] >> (WORD_BITS - shift);
+            for i in (1..NUM_WORDS).rev() {
+                un[i] = (un[i] << shift) | (un[i - 1] >> (WORD_BITS - shift));
             }
-            self = self - rhs.clone();
+            un[0] <<= shift;
+        }
+
+        let v_top = DoubleWord::from(vn(n - 1));
+        let v_next = DoubleWord::from(vn(n - 2));
+
+        for j in (0..=(m - n)).rev() {
+            let top = if j + n < NUM_WORDS {
+                un[j + n]
+            } else {
+                un_overflow
+            };
+
+            // Estimate the quotient word from the top two words of the current dividend
+            let numerator = (DoubleWord::from(top) << WORD_BITS) | DoubleWord::from(un[j + n - 1]);
+            let mut qhat = numerator / v_top;
+            let mut rhat = numerator % v_top;
+
+            while qhat >= BASE
+                || qhat * v_next > ((rhat << WORD_BITS) | DoubleWord::from(un[j + n - 2]))
+            {
+                qhat -= 1;
+                rhat += v_top;
+
+                if rhat >= BASE {
+                    break;
+                }
+            }
+
+            // Multiply and subtract `qhat * vn` from the current dividend
+            let mut mul_carry: DoubleWord = 0;
+            let mut borrow = false;
+            for i in 0..n {
+                let product = qhat * DoubleWord::from(vn(i)) + mul_carry;
+                mul_carry = product >> WORD_BITS;
+
+                let (word, borrow_a) = un[j + i].overflowing_sub(product as Word);
+                let (word, borrow_b) = word.overflowing_sub(Word::from(borrow));
+
+                un[j + i] = word;
+                borrow = borrow_a || borrow_b;
+            }
+
+            // Only whether the top word borrows matters, as it ends up zero either way
+            let (word, borrow_a) = top.overflowing_sub(mul_carry as Word);
+            let (_, borrow_b) = word.overflowing_sub(Word::from(borrow));
+
+            // The estimate was one too large, so add the divisor back once
+            if borrow_a || borrow_b {
+                qhat -= 1;
+
+                carrying_add_words(&mut un[j..j + n], (0..n).map(vn), false);
+            }
+
+            // The top word is now free to hold the quotient word
+            if j + n < NUM_WORDS {
+                un[j + n] = qhat as Word;
+            } else {
+                un_overflow = qhat as Word;
+            }
+        }
+
+        // Denormalize the remainder
+        if shift != 0 {
+            let shift = shift as usize;
+
+            for i in 0..(n - 1) {
+                un[i] = (un[i] >> shift) | (un[i + 1] << (WORD_BITS - shift));
+            }
+            un[n - 1] >>= shift;
+        }
+
+        un_overflow
+    }
+
+    /// Move the quotient left behind by [`long_div_assign`](Self::long_div_assign) for a divisor
+    /// of `n` words down into the lowest words of `self`.
+    fn take_quotient(&mut self, n: usize, top: Word) {
+        self.internal.copy_within(n.., 0);
+        self.internal[NUM_WORDS - n] = top;
+        self.internal[NUM_WORDS - n + 1..].fill(0);
+    }
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Wrapping multiplication. Computes `self * rhs`, wrapping around at the boundary of the
+    /// type.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        if NUM_WORDS >= KARATSUBA_TRUNCATED_THRESHOLD {
+            return self.karatsuba_mul(&rhs.internal).0;
+        }
+
+        let mut internal = [0; NUM_WORDS];
+        mul_into(&mut internal, &self.internal, &rhs.internal);
+
+        BigUInt { internal }
+    }
+
+    /// Calculates `self * rhs`, returning the wrapped product along with whether an overflow
+    /// occurred.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (lo, hi) = self.widening_mul(rhs);
+        (lo, !hi.is_zero())
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None,
         }
     }
+
+    /// Saturating multiplication. Computes `self * rhs`, saturating at
+    /// [`MAX`](crate::BigUInt::MAX) instead of overflowing.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        Some(self.div_rem_ref(&rhs).0)
+    }
+
+    /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        Some(self.div_rem_ref(&rhs).1)
+    }
 }
 
-impl<const NUM_WORDS: usize> MulAssign<Self> for BigUInt<NUM_WORDS> {
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = self.clone() * rhs;
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Multiply `self` by a single word in one linear pass.
+    ///
+    /// Like the multiplication operator, this panics on overflow when `debug_assertions` are
+    /// enabled and wraps around otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert_eq!(<BigUInt<16>>::from(1234u32).mul_word(10), BigUInt::from(12340u32));
+    /// ```
+    pub fn mul_word(mut self, rhs: Word) -> Self {
+        self.mul_word_assign(rhs);
+        self
+    }
+
+    /// Divide `self` by a single word in one linear pass, returning the quotient and the
+    /// remainder.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let (q, r) = <BigUInt<16>>::from(1234u32).div_rem_word(10);
+    ///
+    /// assert_eq!(q, BigUInt::from(123u32));
+    /// assert_eq!(r, 4);
+    /// ```
+    pub fn div_rem_word(mut self, rhs: Word) -> (Self, Word) {
+        let rem = self.div_rem_word_assign(rhs);
+        (self, rem)
+    }
+
+    /// Multiply `self` by a single word in place.
+    fn mul_word_assign(&mut self, rhs: Word) {
+        let rhs = DoubleWord::from(rhs);
+        let mut carry: DoubleWord = 0;
+
+        for word in self.internal.iter_mut() {
+            let t = DoubleWord::from(*word) * rhs + carry;
+
+            *word = t as Word;
+            carry = t >> WORD_BITS;
+        }
+
+        if cfg!(debug_assertions) && carry != 0 {
+            panic!("attempt to multiply with overflow");
+        }
+    }
+
+    /// Divide `self` by a single word in place, returning the remainder.
+    fn div_rem_word_assign(&mut self, rhs: Word) -> Word {
+        if rhs == 0 {
+            panic!("attempt to divide by zero");
+        }
+
+        let divisor = DoubleWord::from(rhs);
+        let mut rem: DoubleWord = 0;
+
+        for word in self.internal.iter_mut().rev() {
+            let current = (rem << WORD_BITS) | DoubleWord::from(*word);
+
+            *word = (current / divisor) as Word;
+            rem = current % divisor;
+        }
+
+        rem as Word
+    }
+
+    /// Divide `self` in place by the non-zero divisor `v`, which has no leading zero words.
+    fn div_assign_words(&mut self, v: &[Word]) {
+        match v.len() {
+            1 => {
+                self.div_rem_word_assign(v[0]);
+            }
+            // A divisor with more words than `self` is always bigger than `self`
+            n if n > NUM_WORDS => *self = Self::MIN,
+            n => {
+                let top = self.long_div_assign(v);
+                self.take_quotient(n, top);
+            }
+        }
+    }
+
+    /// Replace `self` by the remainder of dividing it by the non-zero divisor `v`, which has no
+    /// leading zero words.
+    fn rem_assign_words(&mut self, v: &[Word]) {
+        match v.len() {
+            1 => {
+                let rem = self.div_rem_word_assign(v[0]);
+
+                *self = Self::MIN;
+                self.internal[0] = rem;
+            }
+            // A divisor with more words than `self` is always bigger than `self`
+            n if n > NUM_WORDS => {}
+            n => {
+                self.long_div_assign(v);
+                self.internal[n..].fill(0);
+            }
+        }
     }
 }
-impl<const NUM_WORDS: usize> DivAssign<Self> for BigUInt<NUM_WORDS> {
-    fn div_assign(&mut self, rhs: Self) {
-        *self = self.clone() / rhs;
+
+impl<const NUM_WORDS: usize, const M: usize> MulAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn mul_assign(&mut self, rhs: &BigUInt<M>) {
+        // Karatsuba needs its own product and scratch buffers regardless, of `2 * NUM_WORDS` and
+        // `4 * NUM_WORDS` words. This makes it the only assigning operator which is not in place.
+        let overflow = if NUM_WORDS >= KARATSUBA_TRUNCATED_THRESHOLD && M == NUM_WORDS {
+            let (lo, hi) = self.karatsuba_mul(&rhs.internal);
+            *self = lo;
+
+            !hi.is_zero()
+        } else {
+            mul_assign_words(&mut self.internal, &rhs.internal)
+        };
+
+        if cfg!(debug_assertions) && overflow {
+            panic!("attempt to multiply with overflow");
+        }
     }
 }
-impl<const NUM_WORDS: usize> RemAssign<Self> for BigUInt<NUM_WORDS> {
-    fn rem_assign(&mut self, rhs: Self) {
-        *self = self.clone() % rhs;
+impl<const NUM_WORDS: usize, const M: usize> DivAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn div_assign(&mut self, rhs: &BigUInt<M>) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        self.div_assign_words(&rhs.internal[..significant_words(&rhs.internal)]);
     }
 }
+impl<const NUM_WORDS: usize, const M: usize> RemAssign<&BigUInt<M>> for BigUInt<NUM_WORDS> {
+    fn rem_assign(&mut self, rhs: &BigUInt<M>) {
+        if rhs.is_zero() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+
+        self.rem_assign_words(&rhs.internal[..significant_words(&rhs.internal)]);
+    }
+}
+
+impl<const NUM_WORDS: usize, const M: usize> Mul<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn mul(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut product = *self;
+        product *= rhs;
+        product
+    }
+}
+impl<const NUM_WORDS: usize, const M: usize> Div<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn div(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut quotient = *self;
+        quotient /= rhs;
+        quotient
+    }
+}
+impl<const NUM_WORDS: usize, const M: usize> Rem<&BigUInt<M>> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn rem(self, rhs: &BigUInt<M>) -> Self::Output {
+        let mut remainder = *self;
+        remainder %= rhs;
+        remainder
+    }
+}
+
+forward_ref_binop!(Mul, mul, MulAssign, mul_assign);
+forward_ref_binop!(Div, div, DivAssign, div_assign);
+forward_ref_binop!(Rem, rem, RemAssign, rem_assign);
+
+macro_rules! mul_div_rem_primitive {
+    ($($utype:ty),*) => {$(
+        impl<const NUM_WORDS: usize> Mul<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn mul(mut self, rhs: $utype) -> Self::Output {
+                self *= rhs;
+                self
+            }
+        }
+        impl<const NUM_WORDS: usize> Div<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn div(mut self, rhs: $utype) -> Self::Output {
+                self /= rhs;
+                self
+            }
+        }
+        impl<const NUM_WORDS: usize> Rem<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn rem(mut self, rhs: $utype) -> Self::Output {
+                self %= rhs;
+                self
+            }
+        }
+
+        impl<const NUM_WORDS: usize> MulAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn mul_assign(&mut self, rhs: $utype) {
+                if rhs as u128 <= Word::MAX as u128 {
+                    return self.mul_word_assign(rhs as Word);
+                }
+
+                let overflow = mul_assign_words(&mut self.internal, &u128_to_words(rhs as u128));
+                if cfg!(debug_assertions) && overflow {
+                    panic!("attempt to multiply with overflow");
+                }
+            }
+        }
+        impl<const NUM_WORDS: usize> DivAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn div_assign(&mut self, rhs: $utype) {
+                if rhs == 0 {
+                    panic!("attempt to divide by zero");
+                }
+
+                let words = u128_to_words(rhs as u128);
+                self.div_assign_words(&words[..significant_words(&words)]);
+            }
+        }
+        impl<const NUM_WORDS: usize> RemAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn rem_assign(&mut self, rhs: $utype) {
+                if rhs == 0 {
+                    panic!("attempt to calculate the remainder with a divisor of zero");
+                }
+
+                let words = u128_to_words(rhs as u128);
+                self.rem_assign_words(&words[..significant_words(&words)]);
+            }
+        }
+    )*};
+}
+
+mul_div_rem_primitive!(u8, u16, u32, u64, u128);
 
 #[test]
 fn mul() {
@@ -104,7 +972,166 @@ fn mul() {
             );
         }
     }
+
+    // Full width check
+    for x in (u64::MAX - 10)..u64::MAX {
+        for y in (u64::MAX - 10)..u64::MAX {
+            assert_eq!(
+                <BigUInt<16>>::from(x) * <BigUInt<16>>::from(y),
+                <BigUInt<16>>::from(u128::from(x) * u128::from(y))
+            );
+        }
+    }
+
+    // Wrapping check
+    assert_eq!(
+        BigUInt::MAX.wrapping_mul(BigUInt::MAX),
+        <BigUInt<16>>::from(1u32)
+    );
+}
+
+#[test]
+fn karatsuba() {
+    let mut next = xorshift();
+
+    // Compare against schoolbook multiplication for a couple of sizes around the threshold
+    let mut scratch = [0; 4 * 256];
+    for &n in &[KARATSUBA_THRESHOLD, KARATSUBA_THRESHOLD + 1, 67, 128, 256] {
+        let mut lhs = [0; 256];
+        let mut rhs = [0; 256];
+
+        for i in 0..n {
+            lhs[i] = next() as Word;
+            rhs[i] = next() as Word;
+        }
+
+        // Include the extremes, which stress the carries
+        if n == 128 {
+            lhs = [Word::MAX; 256];
+            rhs = [Word::MAX; 256];
+        }
+
+        let mut expected = [0; 512];
+        let mut actual = [0; 512];
+        mul_into(&mut expected[..2 * n], &lhs[..n], &rhs[..n]);
+        karatsuba_into(&mut actual[..2 * n], &lhs[..n], &rhs[..n], &mut scratch);
+
+        assert_eq!(expected[..], actual[..]);
+    }
+
+    // Through the public interface
+    let a = <BigUInt<64>>::MAX >> 3usize;
+    let b = <BigUInt<64>>::from(u128::MAX) << 100usize;
+    let (lo, hi) = a.widening_mul(b);
+
+    let wide = a.widening_mul_into::<128>(b);
+    assert_eq!(lo.internal, wide.internal[..64]);
+    assert_eq!(hi.internal, wide.internal[64..]);
+    assert_eq!(a.wrapping_mul(b), lo);
+
+    let mut expected = [0; 128];
+    mul_into(&mut expected, &a.internal, &b.internal);
+    assert_eq!(wide.internal[..], expected[..]);
+
+    // Truncated products only switch to Karatsuba for larger numbers
+    let a = <BigUInt<KARATSUBA_TRUNCATED_THRESHOLD>>::MAX >> 3usize;
+    let b = <BigUInt<KARATSUBA_TRUNCATED_THRESHOLD>>::from(u128::MAX) << 100usize;
+    let (lo, _) = a.widening_mul(b);
+    assert_eq!(a.wrapping_mul(b), lo);
+
+    let mut expected = [0; KARATSUBA_TRUNCATED_THRESHOLD];
+    mul_into(&mut expected, &a.internal, &b.internal);
+    assert_eq!(lo.internal, expected);
+}
+
+#[test]
+fn karatsuba_scratch() {
+    for n in 0..20000 {
+        assert!(karatsuba_scratch_len(n) <= 4 * n);
+    }
+}
+
+#[test]
+fn square() {
+    let mut next = xorshift();
+
+    for _ in 0..200 {
+        let a = <BigUInt<16>>::from(next());
+        assert_eq!(a.square(), a * a);
+
+        let a = <BigUInt<16>>::from(next()) << (next() % 64) as usize;
+        assert_eq!(a.widening_square(), a.widening_mul(a));
+
+        let a = <BigUInt<16>>::from(u128::from(next()) << 64 | u128::from(next()));
+        assert_eq!(a.wrapping_square(), a.wrapping_mul(a));
+    }
+
+    assert_eq!(BigUInt::<16>::MIN.square(), BigUInt::MIN);
+    assert_eq!(BigUInt::<16>::MAX.wrapping_square(), BigUInt::from(1u32));
+    assert_eq!(
+        BigUInt::<16>::MAX.widening_square(),
+        (BigUInt::from(1u32), BigUInt::MAX.decrease())
+    );
+
+    // Karatsuba sizes
+    let a = (<BigUInt<128>>::MAX >> 5usize) ^ (<BigUInt<128>>::from(u128::MAX) << 300usize);
+    assert_eq!(a.widening_square(), a.widening_mul(a));
+    assert_eq!(a.wrapping_square(), a.wrapping_mul(a));
+    assert_eq!(
+        BigUInt::<128>::MAX.widening_square(),
+        (BigUInt::from(1u32), BigUInt::MAX.decrease())
+    );
+    let a = <BigUInt<KARATSUBA_TRUNCATED_SQUARE_THRESHOLD>>::MAX >> 3usize;
+    let mut expected = [0; KARATSUBA_TRUNCATED_SQUARE_THRESHOLD];
+    mul_into(&mut expected, &a.internal, &a.internal);
+    assert_eq!(a.wrapping_square().internal, expected);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn square_overflow() {
+    let _ = BigUInt::<16>::MAX.square();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn mul_overflow() {
+    let _ = BigUInt::<16>::MAX * BigUInt::from(2u32);
+}
+#[test]
+fn widening_mul() {
+    // No overflow means no high half
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).widening_mul(BigUInt::from(2u32)),
+        (BigUInt::from(42u32), BigUInt::MIN)
+    );
+
+    // (2^n - 1)^2 = (2^n - 2) * 2^n + 1
+    assert_eq!(
+        <BigUInt<16>>::MAX.widening_mul(BigUInt::MAX),
+        (BigUInt::from(1u32), BigUInt::MAX.decrease())
+    );
+
+    for x in (u128::MAX - 10)..u128::MAX {
+        for y in (u128::MAX - 10)..u128::MAX {
+            let (lo, hi) = <BigUInt<16>>::from(x).widening_mul(BigUInt::from(y));
+            let wide = <BigUInt<16>>::from(x).widening_mul_into::<32>(BigUInt::from(y));
+
+            assert_eq!(wide, <BigUInt<32>>::from(x) * BigUInt::from(y));
+            assert_eq!(lo.internal, wide.internal[..16]);
+            assert_eq!(hi.internal, wide.internal[16..]);
+        }
+    }
+
+    // Truncation into fewer words
+    assert_eq!(
+        <BigUInt<16>>::MAX.widening_mul_into::<8>(BigUInt::MAX),
+        BigUInt::from(1u32)
+    );
 }
+
 #[test]
 fn div() {
     // Check it stays 0
@@ -143,6 +1170,57 @@ fn div() {
     }
 }
 
+/// Returns a xorshift pseudo-random number generator with a fixed seed, so the randomized tests
+/// are reproducible.
+#[cfg(test)]
+fn xorshift() -> impl FnMut() -> u64 {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+#[test]
+fn div_rem() {
+    // Huge dividend, single word divisor
+    let three = <BigUInt<256>>::from(3u32);
+    assert_eq!(BigUInt::MAX / three * three, BigUInt::<256>::MAX);
+    assert!((BigUInt::<256>::MAX % three).is_zero());
+
+    // Multi-word dividends and divisors, checked with `u = q * v + r` and `r < v`
+    let mut next = xorshift();
+
+    for _ in 0..500 {
+        let a = u128::from(next()) << 64 | u128::from(next());
+        let b = u128::from(next() >> (next() % 64)) << (next() % 64) | 1;
+
+        assert_eq!(<BigUInt<32>>::from(a) / BigUInt::from(b), BigUInt::from(a / b));
+        assert_eq!(<BigUInt<32>>::from(a) % BigUInt::from(b), BigUInt::from(a % b));
+    }
+
+    for _ in 0..500 {
+        let u = <BigUInt<32>>::from(u128::from(next()) << 64 | u128::from(next()))
+            * <BigUInt<32>>::from(next());
+        let v = <BigUInt<32>>::from(next() >> (next() % 64)).increase()
+            * <BigUInt<32>>::from(next() >> (next() % 64));
+
+        if v.is_zero() {
+            continue;
+        }
+
+        let (q, r) = u.div_rem_ref(&v);
+        assert_eq!(q, u / v);
+        assert_eq!(r, u % v);
+
+        assert!(r < v);
+        assert_eq!(q * v + r, u);
+    }
+}
+
 #[test]
 fn rem() {
     // Check it stays 0
@@ -180,3 +1258,273 @@ fn rem() {
         }
     }
 }
+
+#[test]
+fn overflowing_mul() {
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).overflowing_mul(BigUInt::from(2u32)),
+        (BigUInt::from(42u32), false)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.overflowing_mul(BigUInt::from(2u32)),
+        (BigUInt::MAX.decrease(), true)
+    );
+}
+
+#[test]
+fn checked_mul() {
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).checked_mul(BigUInt::from(2u32)),
+        Some(BigUInt::from(42u32))
+    );
+    assert_eq!(
+        <BigUInt<32>>::from(u128::MAX).checked_mul(BigUInt::from(u128::MAX)),
+        Some(
+            <BigUInt<32>>::from(u128::MAX)
+                .widening_mul(BigUInt::from(u128::MAX))
+                .0
+        )
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.checked_mul(BigUInt::MIN),
+        Some(BigUInt::MIN)
+    );
+    assert_eq!(BigUInt::<16>::MAX.checked_mul(BigUInt::from(2u32)), None);
+}
+
+#[test]
+fn saturating_mul() {
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).saturating_mul(BigUInt::from(2u32)),
+        BigUInt::from(42u32)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.saturating_mul(BigUInt::from(2u32)),
+        BigUInt::MAX
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.saturating_mul(BigUInt::MIN),
+        BigUInt::MIN
+    );
+}
+
+#[test]
+fn mul_div_rem_primitive() {
+    for x in 10000..10100u64 {
+        assert_eq!(<BigUInt<16>>::from(x) * 250u8, BigUInt::from(x * 250));
+        assert_eq!(<BigUInt<16>>::from(x) * 1000u16, BigUInt::from(x * 1000));
+        assert_eq!(
+            <BigUInt<16>>::from(x) * 100_000u32,
+            BigUInt::from(x * 100_000)
+        );
+        assert_eq!(
+            <BigUInt<16>>::from(x) * (1u128 << 100),
+            BigUInt::from(u128::from(x) << 100)
+        );
+
+        let y = u128::MAX - u128::from(x);
+        assert_eq!(<BigUInt<16>>::from(y) / 10u8, BigUInt::from(y / 10));
+        assert_eq!(<BigUInt<16>>::from(y) / 1000u16, BigUInt::from(y / 1000));
+        assert_eq!(<BigUInt<16>>::from(y) / x, BigUInt::from(y / u128::from(x)));
+        assert_eq!(<BigUInt<16>>::from(y) / (y >> 3), BigUInt::from(8u32));
+        assert_eq!(<BigUInt<16>>::from(y) % 10u8, BigUInt::from(y % 10));
+        assert_eq!(<BigUInt<16>>::from(y) % x, BigUInt::from(y % u128::from(x)));
+        assert_eq!(
+            <BigUInt<16>>::from(y) % (y >> 3),
+            BigUInt::from(y % (y >> 3))
+        );
+    }
+
+    // Divisors bigger than any value of the BigUInt
+    assert_eq!(<BigUInt<1>>::MAX / u128::MAX, BigUInt::MIN);
+    assert_eq!(<BigUInt<1>>::MAX % u128::MAX, BigUInt::MAX);
+    assert_eq!(<BigUInt<1>>::MIN * u128::MAX, BigUInt::MIN);
+
+    let mut x = <BigUInt<16>>::from(7u32);
+    x *= 12u64;
+    x /= 2u128;
+    x %= 40u16;
+    assert_eq!(x, BigUInt::from(2u32));
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn rem_primitive_by_zero() {
+    let _ = <BigUInt<16>>::from(21u32) % 0u64;
+}
+
+#[test]
+fn mul_word() {
+    for x in 10000..10100u64 {
+        for y in 240..250u8 {
+            assert_eq!(
+                <BigUInt<16>>::from(x).mul_word(y as Word),
+                BigUInt::from(x * u64::from(y))
+            );
+        }
+    }
+
+    assert_eq!(BigUInt::<16>::MAX.mul_word(0), BigUInt::MIN);
+    assert_eq!(BigUInt::<16>::MAX.mul_word(1), BigUInt::MAX);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn mul_word_overflow() {
+    let _ = BigUInt::<16>::MAX.mul_word(2);
+}
+
+#[test]
+fn div_rem_word() {
+    for x in (u128::MAX - 100)..u128::MAX {
+        for y in 250..256u16 {
+            let y = y as Word;
+            let (q, r) = <BigUInt<16>>::from(x).div_rem_word(y);
+
+            assert_eq!(q, BigUInt::from(x / y as u128));
+            assert_eq!(r as u128, x % y as u128);
+        }
+    }
+
+    assert_eq!(BigUInt::<16>::MAX.div_rem_word(1), (BigUInt::MAX, 0));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_rem_word_by_zero() {
+    let _ = BigUInt::<16>::MAX.div_rem_word(0);
+}
+
+#[test]
+fn checked_div_rem() {
+    assert_eq!(<BigUInt<16>>::from(21u32).checked_div(BigUInt::MIN), None);
+    assert_eq!(<BigUInt<16>>::from(21u32).checked_rem(BigUInt::MIN), None);
+
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).checked_div(BigUInt::from(4u32)),
+        Some(BigUInt::from(5u32))
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).checked_rem(BigUInt::from(4u32)),
+        Some(BigUInt::from(1u32))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_by_zero() {
+    let _ = <BigUInt<16>>::from(21u32) / BigUInt::MIN;
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn rem_by_zero() {
+    let _ = <BigUInt<16>>::from(21u32) % BigUInt::MIN;
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn mul_div_rem_ref() {
+    let x = <BigUInt<16>>::from(23u32);
+    let y = <BigUInt<16>>::from(5u32);
+
+    assert_eq!(&x * &y, BigUInt::from(115u32));
+    assert_eq!(&x * y, BigUInt::from(115u32));
+    assert_eq!(x * &y, BigUInt::from(115u32));
+    assert_eq!(&x / &y, BigUInt::from(4u32));
+    assert_eq!(&x / y, BigUInt::from(4u32));
+    assert_eq!(&x % &y, BigUInt::from(3u32));
+    assert_eq!(x % &y, BigUInt::from(3u32));
+
+    let mut z = x;
+    z *= &y;
+    z /= &x;
+    z %= &y;
+    assert_eq!(z, BigUInt::MIN);
+}
+
+#[test]
+fn mul_assign_in_place() {
+    let mut next = xorshift();
+
+    // Compare against the widening product, with operands of all kinds of lengths
+    for _ in 0..500 {
+        let mut a = <BigUInt<8>>::MIN;
+        let mut b = <BigUInt<8>>::MIN;
+        for i in 0..8 {
+            a.internal[i] = next() as Word;
+            b.internal[i] = next() as Word;
+        }
+
+        let a = a >> (next() as usize % BigUInt::<8>::BITS);
+        let b = b >> (next() as usize % BigUInt::<8>::BITS);
+
+        let mut product = a;
+        let overflow = mul_assign_words(&mut product.internal, &b.internal);
+        let (lo, hi) = a.widening_mul(b);
+
+        assert_eq!(product, lo);
+        assert_eq!(overflow, !hi.is_zero());
+    }
+
+    let mut x = <BigUInt<32>>::from(u64::MAX);
+    x *= <BigUInt<32>>::from(u64::MAX);
+    x *= u64::MAX;
+    assert_eq!(x, <BigUInt<32>>::from(u64::MAX).pow(3));
+
+    // Through Karatsuba
+    let mut x = <BigUInt<KARATSUBA_TRUNCATED_THRESHOLD>>::from(u128::MAX) << 100usize;
+    x *= <BigUInt<KARATSUBA_TRUNCATED_THRESHOLD>>::from(u128::MAX);
+    assert_eq!(x, <BigUInt<KARATSUBA_TRUNCATED_THRESHOLD>>::from(u128::MAX).square() << 100usize);
+}
+
+#[test]
+fn div_rem_assign_in_place() {
+    let mut next = xorshift();
+
+    for _ in 0..500 {
+        let u = <BigUInt<32>>::from(u128::from(next()) << 64 | u128::from(next()))
+            << (next() % 128) as usize;
+        let d = u128::from(next()) << 64 | u128::from(next());
+        let d = (d >> (next() % 127)).max(1);
+        let v = <BigUInt<32>>::from(d);
+
+        let (q, r) = u.div_rem(v);
+
+        let mut quotient = u;
+        quotient /= v;
+        assert_eq!(quotient, q);
+
+        let mut remainder = u;
+        remainder %= v;
+        assert_eq!(remainder, r);
+
+        assert_eq!(u / d, q);
+        assert_eq!(u % d, r);
+    }
+
+    // A primitive divisor with more words than the dividend
+    assert_eq!(<BigUInt<1>>::MAX / u128::MAX, BigUInt::MIN);
+    assert_eq!(<BigUInt<1>>::MAX % u128::MAX, BigUInt::MAX);
+}
+
+#[test]
+fn mul_div_rem_mixed_width() {
+    let x = <BigUInt<64>>::from(u128::MAX);
+    let y = <BigUInt<32>>::from(u64::MAX);
+
+    let product = x * &y;
+    assert_eq!(product, x.wrapping_mul(BigUInt::from(u64::MAX)));
+    assert_eq!(product / &y, x);
+    assert_eq!(&(product + 5u8) % &y, BigUInt::from(5u8));
+
+    // A divisor wider than `self` may still be smaller
+    let mut z = <BigUInt<16>>::from(100u32);
+    z /= &<BigUInt<64>>::from(7u32);
+    assert_eq!(z, BigUInt::from(14u32));
+    z %= &<BigUInt<64>>::MAX;
+    assert_eq!(z, BigUInt::from(14u32));
+    z *= &<BigUInt<32>>::from(3u32);
+    assert_eq!(z, BigUInt::from(42u32));
+}
@@ -0,0 +1,300 @@
+use crate::BigUInt;
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Like the multiplication operator, this panics on overflow when `debug_assertions` are
+    /// enabled and wraps around otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert_eq!(<BigUInt<16>>::from(3u32).pow(4), BigUInt::from(81u32));
+    /// ```
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::MIN.increase();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+
+            exp /= 2;
+            if exp > 0 {
+                base = base.square();
+            }
+        }
+
+        acc
+    }
+
+    /// Wrapping exponentiation. Computes `self.pow(exp)`, wrapping around at the boundary of the
+    /// type.
+    pub fn wrapping_pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::MIN.increase();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.wrapping_mul(base);
+            }
+
+            exp /= 2;
+            if exp > 0 {
+                base = base.wrapping_square();
+            }
+        }
+
+        acc
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::MIN.increase();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+
+            exp /= 2;
+            if exp > 0 {
+                base = match base.widening_square() {
+                    (square, hi) if hi.is_zero() => square,
+                    _ => return None,
+                };
+            }
+        }
+
+        Some(acc)
+    }
+
+    /// Saturating exponentiation. Computes `self.pow(exp)`, saturating at
+    /// [`MAX`](crate::BigUInt::MAX) instead of overflowing.
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap_or(Self::MAX)
+    }
+
+    /// Create the power of two `2^k`, i.e. a BigUInt with only bit `k` set.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `k` is not smaller than [`BITS`](crate::BigUInt::BITS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert_eq!(<BigUInt<16>>::power_of_two(10), BigUInt::from(1024u32));
+    /// ```
+    pub fn power_of_two(k: usize) -> Self {
+        let mut x = Self::MIN;
+        x.set_bit(k, true);
+        x
+    }
+
+    /// Returns whether `self` is `2^k` for some `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert!(<BigUInt<16>>::from(1024u32).is_power_of_two());
+    /// assert!(!<BigUInt<16>>::from(1000u32).is_power_of_two());
+    /// ```
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// When the result does not fit, this panics when `debug_assertions` are enabled and returns
+    /// `0` otherwise, like the primitive integers do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert_eq!(<BigUInt<16>>::from(1000u32).next_power_of_two(), BigUInt::from(1024u32));
+    /// assert_eq!(<BigUInt<16>>::MIN.next_power_of_two(), BigUInt::from(1u32));
+    /// ```
+    pub fn next_power_of_two(self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(power) => power,
+            None => {
+                if cfg!(debug_assertions) {
+                    panic!("attempt to add with overflow");
+                }
+
+                Self::MIN
+            }
+        }
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`, or `None` if it does
+    /// not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// assert_eq!(
+    ///     <BigUInt<16>>::from(1000u32).checked_next_power_of_two(),
+    ///     Some(BigUInt::from(1024u32))
+    /// );
+    /// assert_eq!(BigUInt::<16>::MAX.checked_next_power_of_two(), None);
+    /// ```
+    pub fn checked_next_power_of_two(self) -> Option<Self> {
+        if self.is_power_of_two() {
+            return Some(self);
+        }
+
+        // For any other value, including 0, this is the bit just above the most significant one
+        let k = self.bits();
+        if k >= Self::BITS {
+            return None;
+        }
+
+        Some(Self::power_of_two(k))
+    }
+}
+
+#[test]
+fn pow() {
+    assert_eq!(<BigUInt<16>>::from(0u32).pow(0), BigUInt::from(1u32));
+    assert_eq!(<BigUInt<16>>::from(0u32).pow(3), BigUInt::MIN);
+    assert_eq!(<BigUInt<16>>::from(1u32).pow(1000), BigUInt::from(1u32));
+
+    for x in 0..20u128 {
+        for exp in 0..20u32 {
+            assert_eq!(<BigUInt<16>>::from(x).pow(exp), BigUInt::from(x.pow(exp)));
+        }
+    }
+}
+
+#[test]
+fn wrapping_pow() {
+    for x in 0..20u128 {
+        for exp in 0..20u32 {
+            assert_eq!(
+                <BigUInt<16>>::from(x).wrapping_pow(exp),
+                BigUInt::from(x.pow(exp))
+            );
+        }
+    }
+
+    // Wraps like multiplication does
+    assert_eq!(
+        <BigUInt<16>>::from(2u32).wrapping_pow(BigUInt::<16>::BITS as u32),
+        BigUInt::MIN
+    );
+    assert_eq!(BigUInt::<16>::MAX.wrapping_pow(2), BigUInt::from(1u32));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn pow_overflow() {
+    let _ = <BigUInt<16>>::from(2u32).pow(BigUInt::<16>::BITS as u32);
+}
+
+#[test]
+fn checked_pow() {
+    for x in 0..20u128 {
+        for exp in 0..20u32 {
+            assert_eq!(
+                <BigUInt<16>>::from(x).checked_pow(exp),
+                Some(BigUInt::from(x.pow(exp)))
+            );
+        }
+    }
+
+    let bits = BigUInt::<16>::BITS as u32;
+    assert_eq!(
+        <BigUInt<16>>::from(2u32).checked_pow(bits - 1),
+        Some(BigUInt::from(1u32) << (BigUInt::<16>::BITS - 1))
+    );
+    assert_eq!(<BigUInt<16>>::from(2u32).checked_pow(bits), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_pow(2), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_pow(1), Some(BigUInt::MAX));
+}
+
+#[test]
+fn saturating_pow() {
+    assert_eq!(
+        <BigUInt<16>>::from(3u32).saturating_pow(4),
+        BigUInt::from(81u32)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(2u32).saturating_pow(BigUInt::<16>::BITS as u32),
+        BigUInt::MAX
+    );
+    assert_eq!(BigUInt::<16>::MAX.saturating_pow(2), BigUInt::MAX);
+    assert_eq!(BigUInt::<16>::MAX.saturating_pow(0), BigUInt::from(1u32));
+}
+
+#[test]
+fn power_of_two() {
+    for k in 0..128 {
+        let power = <BigUInt<16>>::power_of_two(k);
+
+        assert_eq!(power, BigUInt::from(1u128 << k));
+        assert!(power.is_power_of_two());
+        assert_eq!(power.next_power_of_two(), power);
+    }
+
+    let top = <BigUInt<16>>::power_of_two(BigUInt::<16>::BITS - 1);
+    assert_eq!(top, <BigUInt<16>>::from(1u8) << (BigUInt::<16>::BITS - 1));
+    assert!(top.is_power_of_two());
+
+    assert!(!<BigUInt<16>>::MIN.is_power_of_two());
+    assert!(!<BigUInt<16>>::MAX.is_power_of_two());
+}
+
+#[test]
+#[should_panic(expected = "bit index")]
+fn power_of_two_overflow() {
+    let _ = <BigUInt<16>>::power_of_two(BigUInt::<16>::BITS);
+}
+
+#[test]
+fn next_power_of_two() {
+    assert_eq!(<BigUInt<16>>::MIN.next_power_of_two(), BigUInt::from(1u8));
+
+    for x in 1..5000u128 {
+        assert_eq!(
+            <BigUInt<16>>::from(x).next_power_of_two(),
+            BigUInt::from(x.next_power_of_two())
+        );
+        assert_eq!(
+            (<BigUInt<16>>::from(x) << 100usize).checked_next_power_of_two(),
+            Some(<BigUInt<16>>::from(x.next_power_of_two()) << 100usize)
+        );
+    }
+
+    let top = <BigUInt<16>>::power_of_two(BigUInt::<16>::BITS - 1);
+    assert_eq!(top.checked_next_power_of_two(), Some(top));
+    assert_eq!((top + 1u8).checked_next_power_of_two(), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_next_power_of_two(), None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn next_power_of_two_overflow() {
+    let _ = BigUInt::<16>::MAX.next_power_of_two();
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn next_power_of_two_overflow() {
+    assert_eq!(BigUInt::<16>::MAX.next_power_of_two(), BigUInt::MIN);
+}
@@ -0,0 +1,223 @@
+use crate::BigUInt;
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Convert into a [`BigUInt`](crate::BigUInt) of `M` words, discarding the most significant
+    /// words which do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::from(1u8) << <BigUInt<16>>::BITS;
+    ///
+    /// assert_eq!(x.truncate::<16>(), BigUInt::MIN);
+    /// assert_eq!((x + 1u8).truncate::<16>(), BigUInt::from(1u8));
+    /// ```
+    pub fn truncate<const M: usize>(self) -> BigUInt<M> {
+        let mut internal = [0; M];
+
+        let len = crate::min(M, NUM_WORDS);
+        internal[..len].copy_from_slice(&self.internal[..len]);
+
+        BigUInt { internal }
+    }
+
+    /// Losslessly convert into a [`BigUInt`](crate::BigUInt) of `M` words, zero-extending the
+    /// value.
+    ///
+    /// Converting into a [`BigUInt`](crate::BigUInt) with fewer words is rejected at compile
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::MAX;
+    ///
+    /// assert_eq!(x.widen::<32>(), (<BigUInt<32>>::from(1u8) << <BigUInt<16>>::BITS) - 1u8);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<32>>::MAX.widen::<16>();
+    /// ```
+    pub fn widen<const M: usize>(self) -> BigUInt<M> {
+        const {
+            assert!(
+                M >= NUM_WORDS,
+                "cannot widen a BigUInt into one with fewer words"
+            )
+        };
+
+        self.truncate()
+    }
+
+    /// Convert into a [`BigUInt`](crate::BigUInt) of `M` words, returning `None` if the value
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::MAX.widen::<32>();
+    ///
+    /// assert_eq!(x.checked_resize::<16>(), Some(BigUInt::MAX));
+    /// assert_eq!((x + 1u8).checked_resize::<16>(), None);
+    /// ```
+    pub fn checked_resize<const M: usize>(self) -> Option<BigUInt<M>> {
+        if NUM_WORDS > M && self.internal[M..].iter().any(|&word| word != 0) {
+            return None;
+        }
+
+        Some(self.truncate())
+    }
+
+    /// Split into the lowest `K` words and the remaining `H` most significant words.
+    ///
+    /// The two parts have to add up to exactly `NUM_WORDS` words, which is checked at compile
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = (<BigUInt<32>>::from(1u8) << <BigUInt<16>>::BITS) + 2u8;
+    /// let (lo, hi): (BigUInt<16>, BigUInt<16>) = x.split_at_word();
+    ///
+    /// assert_eq!(lo, BigUInt::from(2u8));
+    /// assert_eq!(hi, BigUInt::from(1u8));
+    /// ```
+    pub fn split_at_word<const K: usize, const H: usize>(self) -> (BigUInt<K>, BigUInt<H>) {
+        const {
+            assert!(
+                K + H == NUM_WORDS,
+                "the parts of a split BigUInt have to add up to its number of words"
+            )
+        };
+
+        let mut lo = [0; K];
+        let mut hi = [0; H];
+
+        lo.copy_from_slice(&self.internal[..K]);
+        hi.copy_from_slice(&self.internal[K..]);
+
+        (BigUInt { internal: lo }, BigUInt { internal: hi })
+    }
+
+    /// Create a BigUInt with `lo` as its lowest words and `hi` as its most significant words.
+    ///
+    /// The two parts have to add up to exactly `NUM_WORDS` words, which is checked at compile
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let lo = <BigUInt<16>>::from(2u8);
+    /// let hi = <BigUInt<16>>::from(1u8);
+    ///
+    /// assert_eq!(
+    ///     <BigUInt<32>>::concat(lo, hi),
+    ///     (<BigUInt<32>>::from(1u8) << <BigUInt<16>>::BITS) + 2u8
+    /// );
+    /// ```
+    pub fn concat<const L: usize, const H: usize>(lo: BigUInt<L>, hi: BigUInt<H>) -> Self {
+        const {
+            assert!(
+                L + H == NUM_WORDS,
+                "the parts of a concatenated BigUInt have to add up to its number of words"
+            )
+        };
+
+        let mut internal = [0; NUM_WORDS];
+
+        internal[..L].copy_from_slice(&lo.internal);
+        internal[L..].copy_from_slice(&hi.internal);
+
+        BigUInt { internal }
+    }
+}
+
+#[test]
+fn truncate() {
+    for x in 65500..65600u32 {
+        let wide = <BigUInt<32>>::from(u128::from(x) << 64 | u128::from(x));
+
+        assert_eq!(
+            wide.truncate::<16>(),
+            <BigUInt<16>>::from(u128::from(x) << 64 | u128::from(x))
+        );
+        assert_eq!(wide.truncate::<32>(), wide);
+        assert_eq!(wide.truncate::<64>(), &wide);
+    }
+
+    assert_eq!(<BigUInt<32>>::MAX.truncate::<16>(), BigUInt::MAX);
+    assert_eq!(<BigUInt<32>>::MAX.truncate::<0>(), BigUInt::MIN);
+}
+
+#[test]
+fn widen() {
+    for x in 65500..65600u32 {
+        let narrow = <BigUInt<16>>::from(x);
+
+        assert_eq!(narrow.widen::<16>(), narrow);
+        assert_eq!(narrow.widen::<32>(), <BigUInt<32>>::from(x));
+    }
+
+    assert_eq!(<BigUInt<16>>::MAX.widen::<32>(), &<BigUInt<16>>::MAX);
+}
+
+#[test]
+fn checked_resize() {
+    for x in 65500..65600u32 {
+        let wide = <BigUInt<32>>::from(x);
+
+        assert_eq!(wide.checked_resize::<16>(), Some(<BigUInt<16>>::from(x)));
+        assert_eq!(wide.checked_resize::<64>(), Some(<BigUInt<64>>::from(x)));
+    }
+
+    let top = <BigUInt<32>>::from(1u32) << (BigUInt::<32>::BITS - 1);
+    assert_eq!(top.checked_resize::<16>(), None);
+    assert_eq!(top.checked_resize::<31>(), None);
+    assert_eq!(top.checked_resize::<32>(), Some(top));
+    assert_eq!(<BigUInt<32>>::MIN.checked_resize::<0>(), Some(BigUInt::MIN));
+}
+
+#[test]
+fn split_at_word() {
+    for x in 65500..65600u64 {
+        let value = <BigUInt<32>>::from(u128::from(x) << 64 | u128::from(x + 1));
+
+        let (lo, hi): (BigUInt<8>, BigUInt<24>) = value.split_at_word();
+        assert_eq!(lo, &value.truncate::<8>());
+        assert_eq!(hi, &(value >> BigUInt::<8>::BITS));
+
+        let (lo, hi): (BigUInt<0>, BigUInt<32>) = value.split_at_word();
+        assert_eq!(lo, BigUInt::MIN);
+        assert_eq!(hi, value);
+    }
+}
+
+#[test]
+fn concat() {
+    for x in 65500..65600u64 {
+        let value = <BigUInt<32>>::from(u128::from(x) << 64 | u128::from(x + 1));
+
+        let (lo, hi): (BigUInt<8>, BigUInt<24>) = value.split_at_word();
+        assert_eq!(<BigUInt<32>>::concat(lo, hi), value);
+
+        let (lo, hi): (BigUInt<31>, BigUInt<1>) = value.split_at_word();
+        assert_eq!(<BigUInt<32>>::concat(lo, hi), value);
+    }
+
+    assert_eq!(
+        <BigUInt<32>>::concat(<BigUInt<16>>::MAX, <BigUInt<16>>::MAX),
+        BigUInt::MAX
+    );
+}
@@ -1,82 +1,362 @@
 use crate::{BigUInt, WORD_BITS};
+use core::convert::TryFrom;
 use core::ops::{Shl, Shr, ShlAssign, ShrAssign};
 
-impl<const NUM_WORDS: usize> Shl<usize> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn shl(self, rhs: usize) -> Self::Output {
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Shift left by `rhs` bits, resulting in 0 when `rhs` is larger than or equal to the number
+    /// of bits in `self`.
+    fn shifted_left(mut self, rhs: usize) -> Self {
+        self.shift_left_assign(rhs);
+        self
+    }
+
+    /// Shift right by `rhs` bits, resulting in 0 when `rhs` is larger than or equal to the number
+    /// of bits in `self`.
+    fn shifted_right(mut self, rhs: usize) -> Self {
+        self.shift_right_assign(rhs);
+        self
+    }
+
+    /// Shift left by `rhs` bits in place, resulting in 0 when `rhs` is larger than or equal to
+    /// the number of bits in `self`.
+    fn shift_left_assign(&mut self, rhs: usize) {
+        if rhs >= Self::BITS {
+            *self = Self::MIN;
+            return;
+        }
+
         let word_shift = rhs / WORD_BITS;
         let bit_shift = rhs % WORD_BITS;
 
-        let mut buffer = [0; NUM_WORDS];
+        let buffer = &mut self.internal;
 
         // If we have exact multiple of the bytes
         if bit_shift == 0 {
             // Just shift all the words over by a set amount
-            for word_index in word_shift..NUM_WORDS {
-                buffer[word_index] = self.internal[word_index - word_shift];
-            }
+            buffer.copy_within(..NUM_WORDS - word_shift, word_shift);
         } else {
-            // If we need to take into account some extra bit offset
-            for word_index in (word_shift + 1)..NUM_WORDS {
-                let left_word = self.internal[word_index - word_shift];
-                let right_word = self.internal[word_index - word_shift - 1];
+            // Going from the top down, every word is read before it gets overwritten
+            for word_index in (word_shift + 1..NUM_WORDS).rev() {
+                let left_word = buffer[word_index - word_shift];
+                let right_word = buffer[word_index - word_shift - 1];
 
                 buffer[word_index] =
                     (left_word << bit_shift) | (right_word >> (WORD_BITS - bit_shift));
             }
 
             // The last byte uses 0x00 for it's left word
-            buffer[word_shift] = self.internal[0] << bit_shift;
+            buffer[word_shift] = buffer[0] << bit_shift;
         }
 
-        BigUInt { internal: buffer }
+        buffer[..word_shift].fill(0);
     }
-}
 
-impl<const NUM_WORDS: usize> Shr<usize> for BigUInt<NUM_WORDS> {
-    type Output = Self;
-    fn shr(self, rhs: usize) -> Self::Output {
+    /// Shift right by `rhs` bits in place, resulting in 0 when `rhs` is larger than or equal to
+    /// the number of bits in `self`.
+    fn shift_right_assign(&mut self, rhs: usize) {
+        if rhs >= Self::BITS {
+            *self = Self::MIN;
+            return;
+        }
+
         let word_shift = rhs / WORD_BITS;
         let bit_shift = rhs % WORD_BITS;
 
-        let mut buffer = [0; NUM_WORDS];
+        let buffer = &mut self.internal;
 
         // If we have exact multiple of the bytes
         if bit_shift == 0 {
             // Just shift all the words over by a set amount
-            for word_index in word_shift..NUM_WORDS {
-                buffer[word_index - word_shift] = self.internal[word_index];
-            }
+            buffer.copy_within(word_shift.., 0);
         } else {
-            // If we need to take into account some extra bit offset
+            // Going from the bottom up, every word is read before it gets overwritten
             for word_index in word_shift..(NUM_WORDS - 1) {
-                let left_word = self.internal[word_index + 1];
-                let right_word = self.internal[word_index];
+                let left_word = buffer[word_index + 1];
+                let right_word = buffer[word_index];
 
                 buffer[word_index - word_shift] =
                     (left_word << (WORD_BITS - bit_shift)) | (right_word >> bit_shift);
             }
 
             // The last byte uses 0x00 for it's left word
-            buffer[NUM_WORDS - word_shift - 1] =
-                self.internal[NUM_WORDS - 1] >> (WORD_BITS - bit_shift);
+            buffer[NUM_WORDS - word_shift - 1] = buffer[NUM_WORDS - 1] >> bit_shift;
         }
 
-        BigUInt { internal: buffer }
+        buffer[NUM_WORDS - word_shift..].fill(0);
     }
 }
 
 impl<const NUM_WORDS: usize> ShlAssign<usize> for BigUInt<NUM_WORDS> {
+    /// Shifts `self` left by `rhs` bits in place.
+    ///
+    /// Shifting by the number of bits in `self` or more panics when `debug_assertions` are
+    /// enabled, like it does for the primitive integers, and results in 0 otherwise.
     fn shl_assign(&mut self, rhs: usize) {
-        *self = self.clone() << rhs;
+        if cfg!(debug_assertions) && rhs >= Self::BITS {
+            panic!("attempt to shift left with overflow");
+        }
+
+        self.shift_left_assign(rhs);
     }
 }
 impl<const NUM_WORDS: usize> ShrAssign<usize> for BigUInt<NUM_WORDS> {
+    /// Shifts `self` right by `rhs` bits in place.
+    ///
+    /// Shifting by the number of bits in `self` or more panics when `debug_assertions` are
+    /// enabled, like it does for the primitive integers, and results in 0 otherwise.
     fn shr_assign(&mut self, rhs: usize) {
-        *self = self.clone() >> rhs;
+        if cfg!(debug_assertions) && rhs >= Self::BITS {
+            panic!("attempt to shift right with overflow");
+        }
+
+        self.shift_right_assign(rhs);
+    }
+}
+
+impl<const NUM_WORDS: usize> Shl<usize> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn shl(self, rhs: usize) -> Self::Output {
+        let mut result = *self;
+        result <<= rhs;
+        result
+    }
+}
+impl<const NUM_WORDS: usize> Shl<usize> for BigUInt<NUM_WORDS> {
+    type Output = Self;
+    fn shl(mut self, rhs: usize) -> Self::Output {
+        self <<= rhs;
+        self
+    }
+}
+
+impl<const NUM_WORDS: usize> Shr<usize> for &BigUInt<NUM_WORDS> {
+    type Output = BigUInt<NUM_WORDS>;
+    fn shr(self, rhs: usize) -> Self::Output {
+        let mut result = *self;
+        result >>= rhs;
+        result
+    }
+}
+impl<const NUM_WORDS: usize> Shr<usize> for BigUInt<NUM_WORDS> {
+    type Output = Self;
+    fn shr(mut self, rhs: usize) -> Self::Output {
+        self >>= rhs;
+        self
     }
 }
 
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    pub fn checked_shl(self, rhs: usize) -> Option<Self> {
+        if rhs >= Self::BITS {
+            None
+        } else {
+            Some(self.shifted_left(rhs))
+        }
+    }
+
+    /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    pub fn checked_shr(self, rhs: usize) -> Option<Self> {
+        if rhs >= Self::BITS {
+            None
+        } else {
+            Some(self.shifted_right(rhs))
+        }
+    }
+
+    /// Wrapping shift left. Computes `self << (rhs % BITS)`, masking `rhs` to the number of bits
+    /// in `self`.
+    pub fn wrapping_shl(self, rhs: usize) -> Self {
+        self.shifted_left(Self::mask_shift(rhs))
+    }
+
+    /// Wrapping shift right. Computes `self >> (rhs % BITS)`, masking `rhs` to the number of bits
+    /// in `self`.
+    pub fn wrapping_shr(self, rhs: usize) -> Self {
+        self.shifted_right(Self::mask_shift(rhs))
+    }
+}
+
+impl<const NUM_WORDS: usize> BigUInt<NUM_WORDS> {
+    /// Masks `rhs` to the number of bits in `self`. A `BigUInt<0>` has no bits, so every shift
+    /// amount is masked to zero for it.
+    fn mask_shift(rhs: usize) -> usize {
+        rhs.checked_rem(Self::BITS).unwrap_or(0)
+    }
+
+    /// Shifts `self` left by `rhs` bits, masked to the number of bits in `self`.
+    ///
+    /// Returns the shifted value along with whether `rhs` was larger than or equal to the number
+    /// of bits in `self`.
+    pub fn overflowing_shl(self, rhs: usize) -> (Self, bool) {
+        (self.wrapping_shl(rhs), rhs >= Self::BITS)
+    }
+
+    /// Shifts `self` right by `rhs` bits, masked to the number of bits in `self`.
+    ///
+    /// Returns the shifted value along with whether `rhs` was larger than or equal to the number
+    /// of bits in `self`.
+    pub fn overflowing_shr(self, rhs: usize) -> (Self, bool) {
+        (self.wrapping_shr(rhs), rhs >= Self::BITS)
+    }
+
+    /// Shifts `self` left by `rhs` bits, returning the shifted value and the bits shifted out.
+    ///
+    /// The bits shifted out are placed at the least significant end of the second value, so that
+    /// they can be or-ed into the next more significant number.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is larger than or equal to the number of bits in `self`.
+    pub fn carrying_shl(self, rhs: usize) -> (Self, Self) {
+        if rhs >= Self::BITS {
+            panic!("attempt to shift left with overflow");
+        }
+
+        if rhs == 0 {
+            return (self, Self::MIN);
+        }
+
+        let carry = self >> (Self::BITS - rhs);
+        (self << rhs, carry)
+    }
+
+    /// Shifts `self` right by `rhs` bits, returning the shifted value and the bits shifted out.
+    ///
+    /// The bits shifted out are placed at the most significant end of the second value, so that
+    /// they can be or-ed into the next less significant number.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is larger than or equal to the number of bits in `self`.
+    pub fn carrying_shr(self, rhs: usize) -> (Self, Self) {
+        if rhs >= Self::BITS {
+            panic!("attempt to shift right with overflow");
+        }
+
+        if rhs == 0 {
+            return (self, Self::MIN);
+        }
+
+        let carry = self << (Self::BITS - rhs);
+        (self >> rhs, carry)
+    }
+
+    /// Rotate left by `n` bits, moving the bits shifted out at the most significant end back in
+    /// at the least significant end. Any `n` is taken modulo the number of bits in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::from(0xABu8) << (BigUInt::<16>::BITS - 8);
+    ///
+    /// assert_eq!(x.rotate_left(12), BigUInt::from(0xAB0u32));
+    /// assert_eq!(x.rotate_left(BigUInt::<16>::BITS + 12), BigUInt::from(0xAB0u32));
+    /// ```
+    pub fn rotate_left(self, n: usize) -> Self {
+        // Also covers a `BigUInt<0>`, which `carrying_shl` cannot shift at all
+        let n = Self::mask_shift(n);
+        if n == 0 {
+            return self;
+        }
+
+        let (shifted, carry) = self.carrying_shl(n);
+        shifted | carry
+    }
+
+    /// Rotate right by `n` bits, moving the bits shifted out at the least significant end back in
+    /// at the most significant end. Any `n` is taken modulo the number of bits in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tiny_big_uint::BigUInt;
+    ///
+    /// let x = <BigUInt<16>>::from(0xABu8);
+    /// let expected = (<BigUInt<16>>::from(0xBu8) << (BigUInt::<16>::BITS - 4)) | 0xAu8;
+    ///
+    /// assert_eq!(x.rotate_right(4), expected);
+    /// ```
+    pub fn rotate_right(self, n: usize) -> Self {
+        // Also covers a `BigUInt<0>`, which `carrying_shr` cannot shift at all
+        let n = Self::mask_shift(n);
+        if n == 0 {
+            return self;
+        }
+
+        let (shifted, carry) = self.carrying_shr(n);
+        shifted | carry
+    }
+}
+
+macro_rules! shift_primitive {
+    ($($utype:ty),*) => {$(
+        impl<const NUM_WORDS: usize> Shl<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn shl(self, rhs: $utype) -> Self::Output {
+                self << usize::try_from(rhs).unwrap_or(usize::MAX)
+            }
+        }
+        impl<const NUM_WORDS: usize> Shr<$utype> for BigUInt<NUM_WORDS> {
+            type Output = Self;
+            fn shr(self, rhs: $utype) -> Self::Output {
+                self >> usize::try_from(rhs).unwrap_or(usize::MAX)
+            }
+        }
+        impl<const NUM_WORDS: usize> Shl<$utype> for &BigUInt<NUM_WORDS> {
+            type Output = BigUInt<NUM_WORDS>;
+            fn shl(self, rhs: $utype) -> Self::Output {
+                self << usize::try_from(rhs).unwrap_or(usize::MAX)
+            }
+        }
+        impl<const NUM_WORDS: usize> Shr<$utype> for &BigUInt<NUM_WORDS> {
+            type Output = BigUInt<NUM_WORDS>;
+            fn shr(self, rhs: $utype) -> Self::Output {
+                self >> usize::try_from(rhs).unwrap_or(usize::MAX)
+            }
+        }
+
+        impl<const NUM_WORDS: usize> ShlAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn shl_assign(&mut self, rhs: $utype) {
+                *self <<= usize::try_from(rhs).unwrap_or(usize::MAX);
+            }
+        }
+        impl<const NUM_WORDS: usize> ShrAssign<$utype> for BigUInt<NUM_WORDS> {
+            fn shr_assign(&mut self, rhs: $utype) {
+                *self >>= usize::try_from(rhs).unwrap_or(usize::MAX);
+            }
+        }
+    )*};
+}
+
+shift_primitive!(u8, u16, u32, u64, u128);
+
+#[test]
+fn shift_primitive() {
+    for x in 65500..65600u64 {
+        for y in 20..32u32 {
+            assert_eq!(<BigUInt<128>>::from(x) << y, <BigUInt<128>>::from(x << y));
+            assert_eq!(
+                <BigUInt<128>>::from(x) >> y as u8,
+                <BigUInt<128>>::from(x >> y)
+            );
+            assert_eq!(
+                <BigUInt<128>>::from(x) << u128::from(y),
+                <BigUInt<128>>::from(x << y)
+            );
+        }
+    }
+
+    let mut x = <BigUInt<16>>::from(21u32);
+    x <<= 3u16;
+    x >>= 2u64;
+    assert_eq!(x, BigUInt::from(42u32));
+}
+
 #[test]
 fn lshift() {
     // Bigger Loop check
@@ -96,3 +376,173 @@ fn rshift() {
         }
     }
 }
+
+#[test]
+fn rshift_top_word() {
+    let top = <BigUInt<16>>::from(1u32) << (BigUInt::<16>::BITS - 1);
+
+    assert_eq!(top >> (BigUInt::<16>::BITS - 1), BigUInt::from(1u32));
+    assert_eq!(
+        top >> 3usize,
+        <BigUInt<16>>::from(1u32) << (BigUInt::<16>::BITS - 4)
+    );
+}
+
+#[test]
+fn overflowing_shift() {
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).overflowing_shl(1),
+        (BigUInt::from(42u32), false)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).overflowing_shl(BigUInt::<16>::BITS + 1),
+        (BigUInt::from(42u32), true)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).overflowing_shr(1),
+        (BigUInt::from(21u32), false)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).overflowing_shr(BigUInt::<16>::BITS + 1),
+        (BigUInt::from(21u32), true)
+    );
+
+    // Without any bits, every shift overflows
+    assert_eq!(BigUInt::<0>::MIN.overflowing_shl(3), (BigUInt::MIN, true));
+    assert_eq!(BigUInt::<0>::MIN.overflowing_shr(0), (BigUInt::MIN, true));
+}
+
+#[test]
+fn carrying_shift() {
+    let bits = BigUInt::<16>::BITS;
+
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).carrying_shl(0),
+        (BigUInt::from(21u32), BigUInt::MIN)
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.carrying_shl(4),
+        (BigUInt::MAX << 4usize, BigUInt::from(0xFu32))
+    );
+    assert_eq!(
+        BigUInt::<16>::MAX.carrying_shr(4),
+        (BigUInt::MAX >> 4usize, BigUInt::MAX << (bits - 4))
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(0b101u32).carrying_shr(1),
+        (
+            BigUInt::from(0b10u32),
+            <BigUInt<16>>::from(1u32) << (bits - 1)
+        )
+    );
+}
+
+#[test]
+fn rotate() {
+    let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+
+    // A single word rotates exactly like a primitive of the same width
+    use crate::Word;
+
+    let x = <BigUInt<1>>::from(value as Word);
+    for n in 0..4 * WORD_BITS {
+        assert_eq!(
+            x.rotate_left(n),
+            BigUInt::from((value as Word).rotate_left(n as u32))
+        );
+        assert_eq!(
+            x.rotate_right(n),
+            BigUInt::from((value as Word).rotate_right(n as u32))
+        );
+    }
+
+    let x = <BigUInt<32>>::from(value) << 61usize;
+    let bits = BigUInt::<32>::BITS;
+    for n in (0..3 * bits).step_by(7) {
+        let expected = (x << (n % bits)) | (x >> ((bits - n % bits) % bits));
+
+        assert_eq!(x.rotate_left(n), expected);
+        assert_eq!(expected.rotate_right(n), x);
+        assert_eq!(x.rotate_right(n), x.rotate_left(bits - n % bits));
+    }
+
+    assert_eq!(BigUInt::<16>::MAX.rotate_left(usize::MAX), BigUInt::MAX);
+    assert_eq!(BigUInt::<16>::MIN.rotate_right(3), BigUInt::MIN);
+    assert_eq!(BigUInt::<0>::MIN.rotate_left(3), BigUInt::MIN);
+    assert_eq!(BigUInt::<0>::MIN.rotate_right(3), BigUInt::MIN);
+}
+
+#[test]
+fn wrapping_shift() {
+    let bits = BigUInt::<16>::BITS;
+
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).wrapping_shl(bits + 1),
+        BigUInt::from(42u32)
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).wrapping_shr(bits + 1),
+        BigUInt::from(21u32)
+    );
+    assert_eq!(BigUInt::<16>::MAX.wrapping_shl(bits), BigUInt::MAX);
+    assert_eq!(BigUInt::<16>::MAX.wrapping_shr(2 * bits), BigUInt::MAX);
+
+    assert_eq!(BigUInt::<0>::MIN.wrapping_shl(3), BigUInt::MIN);
+    assert_eq!(BigUInt::<0>::MIN.wrapping_shr(3), BigUInt::MIN);
+}
+
+#[test]
+fn checked_shift() {
+    assert_eq!(
+        <BigUInt<16>>::from(21u32).checked_shl(1),
+        Some(BigUInt::from(42u32))
+    );
+    assert_eq!(
+        <BigUInt<16>>::from(42u32).checked_shr(1),
+        Some(BigUInt::from(21u32))
+    );
+
+    assert_eq!(
+        BigUInt::<16>::MAX.checked_shl(BigUInt::<16>::BITS - 1),
+        Some(BigUInt::MAX << (BigUInt::<16>::BITS - 1))
+    );
+    assert_eq!(BigUInt::<16>::MAX.checked_shl(BigUInt::<16>::BITS), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_shr(BigUInt::<16>::BITS), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_shl(usize::MAX), None);
+    assert_eq!(BigUInt::<16>::MAX.checked_shr(usize::MAX), None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn shl_overflow() {
+    let _ = BigUInt::<16>::MAX << (BigUInt::<16>::BITS + 1);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to shift right with overflow")]
+fn shr_overflow() {
+    let _ = BigUInt::<16>::MAX >> (BigUInt::<16>::BITS + 1);
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn shift_overflow() {
+    assert_eq!(BigUInt::<16>::MAX << BigUInt::<16>::BITS, BigUInt::MIN);
+    assert_eq!(
+        BigUInt::<16>::MAX >> (BigUInt::<16>::BITS + 1),
+        BigUInt::MIN
+    );
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn shift_ref() {
+    let x = <BigUInt<16>>::from(21u32);
+
+    assert_eq!(&x << 1usize, BigUInt::from(42u32));
+    assert_eq!(&x << 1u8, BigUInt::from(42u32));
+    assert_eq!(&x >> 2usize, BigUInt::from(5u32));
+    assert_eq!(&x >> 2u32, BigUInt::from(5u32));
+}